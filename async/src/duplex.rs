@@ -0,0 +1,127 @@
+use crate::{consumer::AsyncConsumer, producer::AsyncProducer};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::future::FusedFuture;
+
+/// Pair of producer and consumer of different ring buffers serviced by a single task.
+///
+/// Useful for full-duplex pipes where one ring buffer is used for each direction.
+pub struct Duplex<P: AsyncProducer, C: AsyncConsumer> {
+    /// Producer of outgoing ring buffer.
+    pub prod: P,
+    /// Consumer of incoming ring buffer.
+    pub cons: C,
+    pop_first: bool,
+}
+
+/// Direction serviced by [`Duplex::service`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Serviced<T> {
+    /// Pending item was pushed to the producer.
+    Pushed,
+    /// Item was popped from the consumer.
+    Popped(T),
+    /// Neither side can make progress anymore.
+    ///
+    /// The consumer is closed and empty, and there is no pending item or the producer is closed too.
+    ///
+    /// If the producer is closed then the pending item (if any) is left untouched.
+    Closed,
+}
+
+impl<P: AsyncProducer, C: AsyncConsumer> Duplex<P, C> {
+    /// Create duplex from producer of outgoing and consumer of incoming ring buffers.
+    pub fn new(prod: P, cons: C) -> Self {
+        Self {
+            prod,
+            cons,
+            pop_first: false,
+        }
+    }
+
+    /// Destructure into producer and consumer.
+    pub fn into_inner(self) -> (P, C) {
+        (self.prod, self.cons)
+    }
+
+    /// Wait until either `item` is pushed to the producer or an item is popped from the consumer.
+    ///
+    /// `item` is taken only when it is pushed. If `item` is `None` then only the consumer is serviced.
+    /// Item is never pushed to the closed producer, so it is not lost and remains in `item` when [`Serviced::Closed`] is returned.
+    ///
+    /// The side checked first alternates between calls so that neither side starves when both are ready.
+    pub fn service<'a>(&'a mut self, item: &'a mut Option<P::Item>) -> ServiceFuture<'a, P, C> {
+        ServiceFuture {
+            owner: self,
+            item,
+            done: false,
+        }
+    }
+}
+
+pub struct ServiceFuture<'a, P: AsyncProducer, C: AsyncConsumer> {
+    owner: &'a mut Duplex<P, C>,
+    item: &'a mut Option<P::Item>,
+    done: bool,
+}
+impl<'a, P: AsyncProducer, C: AsyncConsumer> Unpin for ServiceFuture<'a, P, C> {}
+impl<'a, P: AsyncProducer, C: AsyncConsumer> FusedFuture for ServiceFuture<'a, P, C> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+impl<'a, P: AsyncProducer, C: AsyncConsumer> ServiceFuture<'a, P, C> {
+    fn try_push(&mut self) -> Option<Serviced<C::Item>> {
+        if self.owner.prod.is_closed() {
+            return None;
+        }
+        let item = self.item.take()?;
+        match self.owner.prod.try_push(item) {
+            Ok(()) => Some(Serviced::Pushed),
+            Err(item) => {
+                self.item.replace(item);
+                None
+            }
+        }
+    }
+    fn try_pop(&mut self) -> Option<Serviced<C::Item>> {
+        self.owner.cons.try_pop().map(Serviced::Popped)
+    }
+}
+impl<'a, P: AsyncProducer, C: AsyncConsumer> Future for ServiceFuture<'a, P, C> {
+    type Output = Serviced<C::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            assert!(!self.done);
+            let push_closed = self.item.is_none() || self.owner.prod.is_closed();
+            let pop_closed = self.owner.cons.is_closed();
+            let serviced = if self.owner.pop_first {
+                self.try_pop().or_else(|| self.try_push())
+            } else {
+                self.try_push().or_else(|| self.try_pop())
+            };
+            if let Some(serviced) = serviced {
+                self.owner.pop_first = !self.owner.pop_first;
+                self.done = true;
+                break Poll::Ready(serviced);
+            }
+            if push_closed && pop_closed {
+                self.done = true;
+                break Poll::Ready(Serviced::Closed);
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            if self.item.is_some() {
+                self.owner.prod.register_waker(cx.waker());
+            }
+            self.owner.cons.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
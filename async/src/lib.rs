@@ -8,12 +8,16 @@ extern crate alloc;
 extern crate std;
 
 mod alias;
+#[cfg(feature = "blocking-bridge")]
+pub mod block_on;
+pub mod duplex;
 pub mod rb;
 pub mod traits;
 mod transfer;
 pub mod wrap;
 
 pub use alias::*;
+pub use duplex::{Duplex, Serviced};
 pub use rb::AsyncRb;
 pub use traits::{consumer, producer};
 pub use transfer::async_transfer;
@@ -69,6 +69,28 @@ impl<S: Storage> Observer for AsyncRb<S> {
     fn write_is_held(&self) -> bool {
         self.base.write_is_held()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.base.has_been_full()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.base.clear_overflow_flag()
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.base.high_water_mark()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.base.reset_high_water_mark()
+    }
 }
 
 impl<S: Storage> Producer for AsyncRb<S> {
@@ -2,13 +2,17 @@ use crate::{
     alias::{AsyncHeapCons, AsyncHeapProd, AsyncHeapRb},
     async_transfer,
     traits::*,
+    Duplex, Serviced,
 };
 use alloc::vec::Vec;
 use core::{
     marker::PhantomData,
     sync::atomic::{AtomicUsize, Ordering},
 };
-use futures::task::{noop_waker_ref, AtomicWaker};
+use futures::{
+    future,
+    task::{noop_waker_ref, AtomicWaker},
+};
 #[cfg(feature = "std")]
 use std::sync::Arc;
 
@@ -64,6 +68,52 @@ fn push_pop() {
     );
 }
 
+#[test]
+fn pop_owned() {
+    use futures::stream::{FuturesUnordered, StreamExt};
+    let (prod_a, cons_a) = AsyncHeapRb::<usize>::new(2).split();
+    let (prod_b, cons_b) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async move {
+            let (mut prod_a, mut prod_b) = (prod_a, prod_b);
+            for i in 0..COUNT {
+                prod_a.push(i).await.unwrap();
+                prod_b.push(COUNT + i).await.unwrap();
+            }
+        },
+        async move {
+            let mut futures = FuturesUnordered::new();
+            futures.push(cons_a.pop_owned());
+            futures.push(cons_b.pop_owned());
+            let mut items = Vec::new();
+            while let Some((cons, item)) = futures.next().await {
+                if let Some(item) = item {
+                    items.push(item);
+                    futures.push(cons.pop_owned());
+                }
+            }
+            items.sort();
+            assert!(items.into_iter().eq(0..(2 * COUNT)));
+        },
+    );
+}
+
+#[cfg(feature = "blocking-bridge")]
+#[test]
+fn block_on_push_pop() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(2).split();
+    let pjh = std::thread::spawn(move || {
+        for i in 0..COUNT {
+            prod.block_on_push(i).unwrap();
+        }
+    });
+    for i in 0..COUNT {
+        assert_eq!(cons.block_on_pop(), Some(i));
+    }
+    pjh.join().unwrap();
+    assert_eq!(cons.block_on_pop(), None);
+}
+
 #[test]
 fn push_pop_slice() {
     let (prod, cons) = AsyncHeapRb::<usize>::new(3).split();
@@ -152,6 +202,25 @@ fn read_write() {
     );
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn write_close() {
+    use futures::{AsyncReadExt, AsyncWriteExt};
+    let (mut prod, mut cons) = AsyncHeapRb::<u8>::new(3).split();
+    execute!(
+        async {
+            prod.write_all(&[0, 1, 2, 3, 4]).await.unwrap();
+            AsyncWriteExt::close(&mut prod).await.unwrap();
+        },
+        async {
+            let mut data = Vec::new();
+            assert_eq!(cons.read_to_end(&mut data).await.unwrap(), 5);
+            assert_eq!(data, [0, 1, 2, 3, 4]);
+        },
+    );
+    drop(prod);
+}
+
 #[test]
 fn transfer() {
     use futures::stream::StreamExt;
@@ -181,6 +250,118 @@ fn transfer() {
     );
 }
 
+#[test]
+fn transfer_count() {
+    let (mut src_prod, mut src_cons) = AsyncHeapRb::<usize>::new(3).split();
+    let (mut dst_prod, mut dst_cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async {
+            assert!(src_prod.push_iter_all(0..8).await);
+            src_prod.close();
+        },
+        async {
+            assert_eq!(async_transfer(&mut src_cons, &mut dst_prod, Some(5)).await, 5);
+            assert_eq!(async_transfer(&mut src_cons, &mut dst_prod, None).await, 3);
+            dst_prod.close();
+        },
+        async {
+            let mut items = Vec::new();
+            while let Some(item) = dst_cons.pop().await {
+                items.push(item);
+            }
+            assert!(items.into_iter().eq(0..8));
+        },
+    );
+}
+
+#[test]
+fn stream() {
+    use futures::stream::StreamExt;
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(3).split();
+    execute!(
+        async move {
+            assert!(prod.push_iter_all(0..COUNT).await);
+        },
+        async move {
+            let mut next = 0;
+            let mut stream = cons.stream();
+            while let Some(item) = stream.next().await {
+                assert_eq!(item, next);
+                next += 1;
+            }
+            assert_eq!(next, COUNT);
+        },
+    );
+}
+
+#[test]
+fn forward() {
+    use futures::stream::{self, StreamExt};
+    let (prod, mut cons) = AsyncHeapRb::<usize>::new(3).split();
+    execute!(
+        async move {
+            stream::iter(0..COUNT).map(Ok).forward(prod).await.unwrap();
+        },
+        async move {
+            let mut next = 0;
+            while let Some(item) = cons.next().await {
+                assert_eq!(item, next);
+                next += 1;
+            }
+            assert_eq!(next, COUNT);
+        },
+    );
+}
+
+#[test]
+fn duplex() {
+    let (a_prod, a_cons) = AsyncHeapRb::<usize>::new(2).split();
+    let (b_prod, b_cons) = AsyncHeapRb::<usize>::new(3).split();
+    execute!(
+        async move {
+            let mut duplex = Duplex::new(a_prod, b_cons);
+            let mut next = 0;
+            let mut item = None;
+            let mut received = Vec::new();
+            loop {
+                if item.is_none() && next < COUNT {
+                    item = Some(next);
+                    next += 1;
+                }
+                match duplex.service(&mut item).await {
+                    Serviced::Pushed => {
+                        if next == COUNT && item.is_none() {
+                            duplex.prod.close();
+                            next += 1;
+                        }
+                    }
+                    Serviced::Popped(x) => received.push(x),
+                    Serviced::Closed => break,
+                }
+            }
+            assert!(received.into_iter().eq(0..COUNT));
+        },
+        async move {
+            let (mut src, mut dst) = (a_cons, b_prod);
+            async_transfer(&mut src, &mut dst, None).await;
+        },
+    );
+}
+
+#[test]
+fn duplex_keeps_pending_item() {
+    let (a_prod, a_cons) = AsyncHeapRb::<usize>::new(2).split();
+    let (b_prod, b_cons) = AsyncHeapRb::<usize>::new(2).split();
+    drop((a_cons, b_prod));
+    let mut duplex = Duplex::new(a_prod, b_cons);
+    let mut item = Some(0);
+    execute!(async {
+        assert_eq!(duplex.service(&mut item).await, Serviced::Closed);
+    });
+    assert_eq!(item, Some(0));
+    assert!(duplex.prod.is_empty());
+}
+
 #[test]
 fn wait() {
     let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(3).split();
@@ -203,10 +384,192 @@ fn wait() {
     );
 }
 
+#[test]
+fn wait_shared() {
+    let (mut prod, cons) = AsyncHeapRb::<usize>::new(3).split();
+    let stats = &cons;
+    execute!(
+        async {
+            cons.wait_occupied(2).await;
+            assert_eq!(stats.occupied_len(), 2);
+        },
+        async {
+            prod.push(0).await.unwrap();
+            assert_eq!(stats.occupied_len(), 1);
+            prod.push(1).await.unwrap();
+        },
+    );
+}
+
+#[test]
+fn wait_consumer_progress() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(2).split();
+    prod.try_push(0).unwrap();
+    execute!(async {
+        assert!(!prod.wait_consumer_progress(future::ready(())).await);
+    });
+    execute!(
+        async {
+            assert!(prod.wait_consumer_progress(future::pending::<()>()).await);
+        },
+        async {
+            assert_eq!(cons.pop().await, Some(0));
+        },
+    );
+    prod.try_push(1).unwrap();
+    prod.try_push(2).unwrap();
+    execute!(
+        async {
+            assert!(prod.wait_consumer_progress(future::pending::<()>()).await);
+        },
+        async {
+            let mut items = [0; 2];
+            assert_eq!(cons.pop_exact(&mut items).await, Ok(()));
+            assert_eq!(items, [1, 2]);
+        },
+    );
+    drop(cons);
+    execute!(async {
+        assert!(!prod.wait_consumer_progress(future::pending::<()>()).await);
+    });
+}
+
+#[test]
+fn push_iter_all_counted() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async {
+            assert_eq!(prod.push_iter_all_counted(0..COUNT).await, Ok(COUNT));
+            assert_eq!(prod.push_iter_all_counted(0..COUNT).await, Err(2));
+        },
+        async {
+            for i in 0..COUNT {
+                assert_eq!(cons.pop().await, Some(i));
+            }
+            cons.wait_occupied(2).await;
+            cons.close();
+        },
+    );
+}
+
+#[test]
+fn explicit_close() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async {
+            assert_eq!(cons.pop().await, Some(0));
+            assert_eq!(cons.pop().await, None);
+            assert!(cons.is_closed());
+        },
+        async {
+            prod.push(0).await.unwrap();
+            prod.close();
+        },
+    );
+    assert!(prod.is_closed());
+    assert!(prod.is_empty());
+    execute!(async {
+        assert_eq!(prod.push(1).await, Err(1));
+    });
+    assert!(prod.is_empty());
+
+    let (mut prod, cons) = AsyncHeapRb::<usize>::new(1).split();
+    execute!(
+        async {
+            prod.push(0).await.unwrap();
+            assert_eq!(prod.push(1).await, Err(1));
+        },
+        async {
+            cons.wait_occupied(1).await;
+            cons.close();
+        },
+    );
+}
+
+#[test]
+fn pop_iter_into() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(4).split();
+    let mut out = Vec::new();
+
+    prod.try_push(0).unwrap();
+    execute!(async {
+        assert_eq!(cons.pop_iter_into(&mut out, 3).await, Ok(1));
+    });
+
+    prod.push_iter(1..5);
+    execute!(async {
+        assert_eq!(cons.pop_iter_into(&mut out, 3).await, Ok(3));
+        assert_eq!(cons.pop_iter_into(&mut out, 0).await, Ok(0));
+        assert_eq!(cons.pop_iter_into(&mut out, 3).await, Ok(1));
+    });
+
+    execute!(
+        async {
+            assert_eq!(cons.pop_iter_into(&mut out, 3).await, Ok(1));
+        },
+        async {
+            prod.push(5).await.unwrap();
+        },
+    );
+
+    prod.try_push(6).unwrap();
+    drop(prod);
+    execute!(async {
+        assert_eq!(cons.pop_iter_into(&mut out, 3).await, Err(1));
+        assert_eq!(cons.pop_iter_into(&mut out, 3).await, Err(0));
+    });
+    assert_eq!(out, (0..7).collect::<Vec<_>>());
+}
+
+#[test]
+fn peek() {
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async {
+            assert_eq!(cons.peek().await, Some(&0));
+            assert_eq!(cons.occupied_len(), 1);
+            assert_eq!(cons.pop().await, Some(0));
+            assert_eq!(cons.peek().await, None);
+        },
+        async {
+            prod.push(0).await.unwrap();
+            prod.wait_vacant(2).await;
+            prod.close();
+        },
+    );
+}
+
+#[test]
+fn wait_closed() {
+    let (prod, cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async {
+            prod.wait_closed().await;
+            assert!(prod.is_closed());
+        },
+        async {
+            cons.wait_occupied(0).await;
+            drop(cons);
+        },
+    );
+
+    let (prod, cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async {
+            cons.wait_closed().await;
+            assert!(cons.is_closed());
+        },
+        async {
+            prod.wait_vacant(0).await;
+            drop(prod);
+        },
+    );
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn drop_close_prod() {
-    let (prod, mut cons) = AsyncHeapRb::<usize>::new(1).split();
+    let (prod, cons) = AsyncHeapRb::<usize>::new(1).split();
     let stage = Arc::new(AtomicUsize::new(0));
     let stage_clone = stage.clone();
     let t0 = std::thread::spawn(move || {
@@ -229,7 +592,7 @@ fn drop_close_prod() {
 #[cfg(feature = "std")]
 #[test]
 fn drop_close_cons() {
-    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(1).split();
+    let (mut prod, cons) = AsyncHeapRb::<usize>::new(1).split();
     let stage = Arc::new(AtomicUsize::new(0));
     let stage_clone = stage.clone();
     let t0 = std::thread::spawn(move || {
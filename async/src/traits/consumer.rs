@@ -3,7 +3,7 @@ use core::{
     pin::Pin,
     task::{Context, Poll, Waker},
 };
-use futures::future::FusedFuture;
+use futures::{future::FusedFuture, Stream};
 use ringbuf::traits::Consumer;
 #[cfg(feature = "std")]
 use std::io;
@@ -11,10 +11,15 @@ use std::io;
 pub trait AsyncConsumer: Consumer {
     fn register_waker(&self, waker: &Waker);
 
-    fn close(&mut self);
-    /// Whether the corresponding producer was closed.
+    /// Close the consumer explicitly without dropping the handle.
+    ///
+    /// The producer is woken and observes the closure, its pending futures complete with an error.
+    ///
+    /// The handle stays usable for inspection of the ring buffer, items remaining in it can still be popped.
+    fn close(&self);
+    /// Whether the corresponding producer or this consumer was closed.
     fn is_closed(&self) -> bool {
-        !self.write_is_held()
+        !self.write_is_held() || !self.read_is_held()
     }
 
     /// Pop item from the ring buffer waiting asynchronously if the buffer is empty.
@@ -26,12 +31,50 @@ pub trait AsyncConsumer: Consumer {
         PopFuture { owner: self, done: false }
     }
 
+    /// Wait for an item to appear in the ring buffer and return a reference to it without removing.
+    ///
+    /// Future returns:
+    /// + `Some(item)` - a reference to the eldest item.
+    /// + `None` - the buffer is empty and the corresponding producer was dropped.
+    ///
+    /// The consumer stays borrowed while the reference is alive, so the item cannot be removed in the meantime.
+    fn peek(&mut self) -> PeekFuture<'_, Self> {
+        PeekFuture { owner: Some(self) }
+    }
+
+    /// Pop item from the ring buffer blocking the current thread if the buffer is empty.
+    ///
+    /// Returns `None` if the buffer is empty and the corresponding producer was dropped.
+    #[cfg(feature = "blocking-bridge")]
+    fn block_on_pop(&mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        crate::block_on::block_on(self.pop())
+    }
+
+    /// Pop item from the ring buffer taking ownership of the consumer.
+    ///
+    /// Unlike [`Self::pop`] the returned future doesn't borrow the consumer,
+    /// so it can be stored in collections like `FuturesUnordered`.
+    ///
+    /// Future returns the consumer back along with:
+    /// + `Some(item)` - an item is taken.
+    /// + `None` - the buffer is empty and the corresponding producer was dropped.
+    fn pop_owned(self) -> PopOwnedFuture<Self>
+    where
+        Self: Sized,
+    {
+        PopOwnedFuture { owner: Some(self) }
+    }
+
     /// Wait for the buffer to contain at least `count` items or to close.
     ///
     /// In debug mode panics if `count` is greater than buffer capacity.
     ///
-    /// The method takes `&mut self` because only single [`WaitOccupiedFuture`] is allowed at a time.
-    fn wait_occupied(&mut self, count: usize) -> WaitOccupiedFuture<'_, Self> {
+    /// The method takes `&self` so that the consumer may be inspected while waiting.
+    /// However, only single waiting future should exist at a time because only the last registered waker is woken.
+    fn wait_occupied(&self, count: usize) -> WaitOccupiedFuture<'_, Self> {
         debug_assert!(count <= self.capacity().get());
         WaitOccupiedFuture {
             owner: self,
@@ -64,6 +107,35 @@ pub trait AsyncConsumer: Consumer {
         }
     }
 
+    /// Wait for the corresponding producer to be closed regardless of the ring buffer state.
+    ///
+    /// Only single waiting future should exist at a time, see [`Self::wait_occupied`].
+    fn wait_closed(&self) -> WaitClosedFuture<'_, Self> {
+        WaitClosedFuture { owner: self, done: false }
+    }
+
+    /// Wait for items and move at most `max` of them into `out`.
+    ///
+    /// Resolves as soon as there is at least one item, so it doesn't wait for `max` items to be available.
+    ///
+    /// Future returns:
+    /// + `Ok(count)` - `count` items were moved.
+    /// + `Err(count)` - the corresponding producer was dropped and all remaining items (`count` of them) were moved.
+    fn pop_iter_into<'a: 'b, 'b, E: Extend<Self::Item>>(&'a mut self, out: &'b mut E, max: usize) -> PopIterFuture<'a, 'b, Self, E> {
+        PopIterFuture {
+            owner: self,
+            out: Some(out),
+            max,
+        }
+    }
+
+    /// Returns a [`Stream`] of items popped from the ring buffer.
+    ///
+    /// The stream terminates when the buffer is empty and the corresponding producer was dropped.
+    fn stream(&mut self) -> ConsStream<'_, Self> {
+        ConsStream { owner: self }
+    }
+
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>
     where
         Self: Unpin,
@@ -140,6 +212,122 @@ impl<'a, A: AsyncConsumer> Future for PopFuture<'a, A> {
     }
 }
 
+pub struct PopIterFuture<'a, 'b, A: AsyncConsumer + ?Sized, E: Extend<A::Item>> {
+    owner: &'a mut A,
+    out: Option<&'b mut E>,
+    max: usize,
+}
+impl<'a, 'b, A: AsyncConsumer, E: Extend<A::Item>> Unpin for PopIterFuture<'a, 'b, A, E> {}
+impl<'a, 'b, A: AsyncConsumer, E: Extend<A::Item>> FusedFuture for PopIterFuture<'a, 'b, A, E> {
+    fn is_terminated(&self) -> bool {
+        self.out.is_none()
+    }
+}
+impl<'a, 'b, A: AsyncConsumer, E: Extend<A::Item>> Future for PopIterFuture<'a, 'b, A, E> {
+    type Output = Result<usize, usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            let closed = self.owner.is_closed();
+            let out = self.out.take().unwrap();
+            let max = self.max;
+            let mut count = 0;
+            out.extend(self.owner.pop_iter().take(max).inspect(|_| count += 1));
+            if closed && self.owner.is_empty() {
+                break Poll::Ready(Err(count));
+            }
+            if count > 0 || max == 0 {
+                break Poll::Ready(Ok(count));
+            }
+            self.out.replace(out);
+            if waker_registered {
+                break Poll::Pending;
+            }
+            self.owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
+
+pub struct PeekFuture<'a, A: AsyncConsumer + ?Sized> {
+    owner: Option<&'a A>,
+}
+impl<'a, A: AsyncConsumer> Unpin for PeekFuture<'a, A> {}
+impl<'a, A: AsyncConsumer> FusedFuture for PeekFuture<'a, A> {
+    fn is_terminated(&self) -> bool {
+        self.owner.is_none()
+    }
+}
+impl<'a, A: AsyncConsumer> Future for PeekFuture<'a, A> {
+    type Output = Option<&'a A::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let owner = self.owner.unwrap();
+        let mut waker_registered = false;
+        loop {
+            let closed = owner.is_closed();
+            if let Some(item) = owner.try_peek() {
+                self.owner = None;
+                break Poll::Ready(Some(item));
+            }
+            if closed {
+                self.owner = None;
+                break Poll::Ready(None);
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
+
+pub struct ConsStream<'a, A: AsyncConsumer + ?Sized> {
+    owner: &'a mut A,
+}
+impl<'a, A: AsyncConsumer + ?Sized> Unpin for ConsStream<'a, A> {}
+impl<'a, A: AsyncConsumer + Unpin + ?Sized> Stream for ConsStream<'a, A> {
+    type Item = A::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        AsyncConsumer::poll_next(Pin::new(&mut *self.owner), cx)
+    }
+}
+
+pub struct PopOwnedFuture<A: AsyncConsumer> {
+    owner: Option<A>,
+}
+impl<A: AsyncConsumer> Unpin for PopOwnedFuture<A> {}
+impl<A: AsyncConsumer> FusedFuture for PopOwnedFuture<A> {
+    fn is_terminated(&self) -> bool {
+        self.owner.is_none()
+    }
+}
+impl<A: AsyncConsumer> Future for PopOwnedFuture<A> {
+    type Output = (A, Option<A::Item>);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            let owner = self.owner.as_mut().unwrap();
+            let closed = owner.is_closed();
+            if let Some(item) = owner.try_pop() {
+                break Poll::Ready((self.owner.take().unwrap(), Some(item)));
+            }
+            if closed {
+                break Poll::Ready((self.owner.take().unwrap(), None));
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
+
 pub struct PopSliceFuture<'a, 'b, A: AsyncConsumer + ?Sized>
 where
     A::Item: Copy,
@@ -264,3 +452,33 @@ impl<'a, A: AsyncConsumer> Future for WaitOccupiedFuture<'a, A> {
         }
     }
 }
+
+pub struct WaitClosedFuture<'a, A: AsyncConsumer + ?Sized> {
+    owner: &'a A,
+    done: bool,
+}
+impl<'a, A: AsyncConsumer> Unpin for WaitClosedFuture<'a, A> {}
+impl<'a, A: AsyncConsumer> FusedFuture for WaitClosedFuture<'a, A> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+impl<'a, A: AsyncConsumer> Future for WaitClosedFuture<'a, A> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            assert!(!self.done);
+            if self.owner.is_closed() {
+                self.done = true;
+                break Poll::Ready(());
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            self.owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
@@ -12,10 +12,16 @@ use std::io;
 pub trait AsyncProducer: Producer {
     fn register_waker(&self, waker: &Waker);
 
-    fn close(&mut self);
-    /// Whether the corresponding consumer was closed.
+    /// Close the producer explicitly, e.g. to signal the end of the stream without dropping the handle.
+    ///
+    /// The consumer is woken and observes the closure, its pending futures complete with the remaining items and then `None`.
+    ///
+    /// The handle stays usable for inspection of the ring buffer.
+    /// Pushing after closing is an error: futures of this trait fail returning items back, like when the consumer is closed.
+    fn close(&self);
+    /// Whether the corresponding consumer or this producer was closed.
     fn is_closed(&self) -> bool {
-        !self.read_is_held()
+        !self.read_is_held() || !self.write_is_held()
     }
 
     /// Push item to the ring buffer waiting asynchronously if the buffer is full.
@@ -30,24 +36,48 @@ pub trait AsyncProducer: Producer {
         }
     }
 
+    /// Push item to the ring buffer blocking the current thread if the buffer is full.
+    ///
+    /// Returns `Err(item)` if the corresponding consumer was dropped.
+    #[cfg(feature = "blocking-bridge")]
+    fn block_on_push(&mut self, item: Self::Item) -> Result<(), Self::Item>
+    where
+        Self: Sized,
+    {
+        crate::block_on::block_on(self.push(item))
+    }
+
     /// Push items from iterator waiting asynchronously if the buffer is full.
     ///
     /// Future returns:
-    /// + `Ok` - iterator ended.
-    /// + `Err(iter)` - the corresponding consumer was dropped, remaining iterator is returned back.
+    /// + `true` - iterator ended.
+    /// + `false` - the corresponding consumer was dropped.
     fn push_iter_all<I: Iterator<Item = Self::Item>>(&mut self, iter: I) -> PushIterFuture<'_, Self, I> {
         PushIterFuture {
             owner: self,
             iter: Some(iter.peekable()),
+            count: 0,
         }
     }
 
+    /// Same as [`Self::push_iter_all`] but also reports the number of items pushed.
+    ///
+    /// Future returns:
+    /// + `Ok(count)` - iterator ended.
+    /// + `Err(count)` - the corresponding consumer was dropped.
+    ///
+    /// *Note that the iterator may be advanced by one more item than pushed when the consumer is dropped.*
+    fn push_iter_all_counted<I: Iterator<Item = Self::Item>>(&mut self, iter: I) -> PushIterCountedFuture<'_, Self, I> {
+        PushIterCountedFuture(self.push_iter_all(iter))
+    }
+
     /// Wait for the buffer to have at least `count` free places for items or to close.
     ///
     /// In debug mode panics if `count` is greater than buffer capacity.
     ///
-    /// The method takes `&mut self` because only single [`WaitVacantFuture`] is allowed at a time.
-    fn wait_vacant(&mut self, count: usize) -> WaitVacantFuture<'_, Self> {
+    /// The method takes `&self` so that the producer may be inspected while waiting.
+    /// However, only single waiting future should exist at a time because only the last registered waker is woken.
+    fn wait_vacant(&self, count: usize) -> WaitVacantFuture<'_, Self> {
         debug_assert!(count <= self.capacity().get());
         WaitVacantFuture {
             owner: self,
@@ -72,6 +102,35 @@ pub trait AsyncProducer: Producer {
         }
     }
 
+    /// Wait for the consumer to remove any items from the ring buffer within the time window given by `timer` future.
+    ///
+    /// Future returns:
+    /// + `true` - the consumer advanced the head of the ring buffer since the call.
+    /// + `false` - `timer` completed and the head is still at the same position, or the corresponding consumer was dropped.
+    ///
+    /// Takes arbitrary future as a timer, so it doesn't depend on any specific async runtime.
+    /// Can be used to detect a hung consumer without waiting for the buffer to become full.
+    ///
+    /// Progress is detected by comparing read indices that wrap around every `2 * capacity` items.
+    /// The future borrows the producer, so no items can be pushed while it's alive and the consumer is able
+    /// to remove at most `capacity` items, that is always detected.
+    ///
+    /// Only single waiting future should exist at a time, see [`Self::wait_vacant`].
+    fn wait_consumer_progress<T: Future + Unpin>(&self, timer: T) -> WaitProgressFuture<'_, Self, T> {
+        WaitProgressFuture {
+            owner: self,
+            head: self.read_index(),
+            timer: Some(timer),
+        }
+    }
+
+    /// Wait for the corresponding consumer to be closed regardless of the ring buffer state.
+    ///
+    /// Only single waiting future should exist at a time, see [`Self::wait_vacant`].
+    fn wait_closed(&self) -> WaitClosedFuture<'_, Self> {
+        WaitClosedFuture { owner: self, done: false }
+    }
+
     fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
         let mut waker_registered = false;
         loop {
@@ -195,6 +254,7 @@ where
 pub struct PushIterFuture<'a, A: AsyncProducer + ?Sized, I: Iterator<Item = A::Item>> {
     owner: &'a mut A,
     iter: Option<Peekable<I>>,
+    count: usize,
 }
 impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> Unpin for PushIterFuture<'a, A, I> {}
 impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> FusedFuture for PushIterFuture<'a, A, I> {
@@ -202,19 +262,17 @@ impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> FusedFuture for PushIter
         self.iter.is_none() || self.owner.is_closed()
     }
 }
-impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> Future for PushIterFuture<'a, A, I> {
-    type Output = bool;
-
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> PushIterFuture<'a, A, I> {
+    fn poll_counted(&mut self, cx: &mut Context<'_>) -> Poll<Result<usize, usize>> {
         let mut waker_registered = false;
         loop {
             let mut iter = self.iter.take().unwrap();
             if self.owner.is_closed() {
-                break Poll::Ready(false);
+                break Poll::Ready(Err(self.count));
             }
-            self.owner.push_iter(&mut iter);
+            self.count += self.owner.push_iter(&mut iter);
             if iter.peek().is_none() {
-                break Poll::Ready(true);
+                break Poll::Ready(Ok(self.count));
             }
             self.iter.replace(iter);
             if waker_registered {
@@ -225,6 +283,28 @@ impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> Future for PushIterFutur
         }
     }
 }
+impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> Future for PushIterFuture<'a, A, I> {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_counted(cx).map(|r| r.is_ok())
+    }
+}
+
+pub struct PushIterCountedFuture<'a, A: AsyncProducer + ?Sized, I: Iterator<Item = A::Item>>(PushIterFuture<'a, A, I>);
+impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> Unpin for PushIterCountedFuture<'a, A, I> {}
+impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> FusedFuture for PushIterCountedFuture<'a, A, I> {
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+impl<'a, A: AsyncProducer, I: Iterator<Item = A::Item>> Future for PushIterCountedFuture<'a, A, I> {
+    type Output = Result<usize, usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_counted(cx)
+    }
+}
 
 pub struct WaitVacantFuture<'a, A: AsyncProducer + ?Sized> {
     owner: &'a A,
@@ -256,3 +336,70 @@ impl<'a, A: AsyncProducer> Future for WaitVacantFuture<'a, A> {
         }
     }
 }
+
+pub struct WaitProgressFuture<'a, A: AsyncProducer + ?Sized, T: Future + Unpin> {
+    owner: &'a A,
+    head: usize,
+    timer: Option<T>,
+}
+impl<'a, A: AsyncProducer, T: Future + Unpin> Unpin for WaitProgressFuture<'a, A, T> {}
+impl<'a, A: AsyncProducer, T: Future + Unpin> FusedFuture for WaitProgressFuture<'a, A, T> {
+    fn is_terminated(&self) -> bool {
+        self.timer.is_none()
+    }
+}
+impl<'a, A: AsyncProducer, T: Future + Unpin> Future for WaitProgressFuture<'a, A, T> {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            let mut timer = self.timer.take().unwrap();
+            if self.owner.read_index() != self.head {
+                break Poll::Ready(true);
+            }
+            if self.owner.is_closed() {
+                break Poll::Ready(false);
+            }
+            if Pin::new(&mut timer).poll(cx).is_ready() {
+                break Poll::Ready(self.owner.read_index() != self.head);
+            }
+            self.timer.replace(timer);
+            if waker_registered {
+                break Poll::Pending;
+            }
+            self.owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
+
+pub struct WaitClosedFuture<'a, A: AsyncProducer + ?Sized> {
+    owner: &'a A,
+    done: bool,
+}
+impl<'a, A: AsyncProducer> Unpin for WaitClosedFuture<'a, A> {}
+impl<'a, A: AsyncProducer> FusedFuture for WaitClosedFuture<'a, A> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+impl<'a, A: AsyncProducer> Future for WaitClosedFuture<'a, A> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut waker_registered = false;
+        loop {
+            assert!(!self.done);
+            if self.owner.is_closed() {
+                self.done = true;
+                break Poll::Ready(());
+            }
+            if waker_registered {
+                break Poll::Pending;
+            }
+            self.owner.register_waker(cx.waker());
+            waker_registered = true;
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{consumer::AsyncConsumer, producer::AsyncProducer};
+use ringbuf::transfer;
 
 /// Tranfer data from one ring buffer to another.
 ///
+/// Waits for items in `src` and vacant space in `dst` and then moves as many items as possible at once.
+///
 /// `count` is the number of items to transfer.
 /// The number of actually transfered items is returned.
 ///
@@ -16,23 +19,23 @@ pub async fn async_transfer<T, As: AsyncConsumer<Item = T>, Ad: AsyncProducer<It
     count: Option<usize>,
 ) -> usize {
     let mut actual_count = 0;
-    // TODO: Transfer multiple items at once.
     loop {
-        if count.as_ref().map_or(false, |n| actual_count == *n) {
+        let remaining = count.map(|n| n - actual_count);
+        if remaining == Some(0) {
+            break;
+        }
+
+        src.wait_occupied(1).await;
+        if src.is_empty() {
+            // `src` is closed.
+            break;
+        }
+        dst.wait_vacant(1).await;
+        if dst.is_closed() {
             break;
         }
-        actual_count += 1;
 
-        match dst
-            .push(match src.pop().await {
-                Some(item) => item,
-                None => break,
-            })
-            .await
-        {
-            Ok(()) => (),
-            Err(_item) => break,
-        };
+        actual_count += transfer(src, dst, remaining);
     }
     actual_count
 }
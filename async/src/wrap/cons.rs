@@ -9,7 +9,7 @@ use futures::Stream;
 use ringbuf::{
     traits::{
         consumer::{Consumer, DelegateConsumer},
-        Observer,
+        Observer, RingBuffer,
     },
     wrap::Wrap,
 };
@@ -24,11 +24,14 @@ impl<R: AsyncRbRef> AsyncConsumer for AsyncCons<R> {
     }
 
     #[inline]
-    fn close(&mut self) {
-        drop(self.base.take());
+    fn close(&self) {
+        unsafe { self.rb().hold_read(false) };
     }
 }
 
+/// Items are popped one by one.
+///
+/// [`Stream::poll_next`] waits for an item and returns `None` when the buffer is empty and the producer is closed or dropped.
 impl<R: AsyncRbRef> Stream for AsyncCons<R> {
     type Item = <R::Rb as Observer>::Item;
 
@@ -9,7 +9,7 @@ use ringbuf::{
 };
 
 pub struct AsyncWrap<R: AsyncRbRef, const P: bool, const C: bool> {
-    base: Option<Direct<R, P, C>>,
+    base: Direct<R, P, C>,
 }
 
 pub type AsyncProd<R> = AsyncWrap<R, true, false>;
@@ -17,9 +17,7 @@ pub type AsyncCons<R> = AsyncWrap<R, false, true>;
 
 impl<R: AsyncRbRef, const P: bool, const C: bool> AsyncWrap<R, P, C> {
     pub unsafe fn new(rb: R) -> Self {
-        Self {
-            base: Some(Direct::new(rb)),
-        }
+        Self { base: Direct::new(rb) }
     }
 
     pub fn observe(&self) -> Obs<R> {
@@ -29,11 +27,13 @@ impl<R: AsyncRbRef, const P: bool, const C: bool> AsyncWrap<R, P, C> {
 
 impl<R: AsyncRbRef, const P: bool, const C: bool> Based for AsyncWrap<R, P, C> {
     type Base = Direct<R, P, C>;
+    #[inline]
     fn base(&self) -> &Self::Base {
-        self.base.as_ref().unwrap()
+        &self.base
     }
+    #[inline]
     fn base_mut(&mut self) -> &mut Self::Base {
-        self.base.as_mut().unwrap()
+        &mut self.base
     }
 }
 
@@ -43,7 +43,7 @@ impl<R: AsyncRbRef, const P: bool, const C: bool> Wrap for AsyncWrap<R, P, C> {
         self.base().rb_ref()
     }
     fn into_rb_ref(self) -> R {
-        self.base.unwrap().into_rb_ref()
+        self.base.into_rb_ref()
     }
 }
 
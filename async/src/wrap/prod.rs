@@ -6,12 +6,10 @@ use core::{
 #[cfg(feature = "std")]
 use futures::io::AsyncWrite;
 use futures::{ready, Sink};
-#[cfg(feature = "std")]
-use ringbuf::traits::RingBuffer;
 use ringbuf::{
     traits::{
         producer::{DelegateProducer, Producer},
-        Observer,
+        Observer, RingBuffer,
     },
     wrap::Wrap,
 };
@@ -26,11 +24,15 @@ impl<R: AsyncRbRef> AsyncProducer for AsyncProd<R> {
     }
 
     #[inline]
-    fn close(&mut self) {
-        drop(self.base.take());
+    fn close(&self) {
+        unsafe { self.rb().hold_write(false) };
     }
 }
 
+/// Items are pushed one by one.
+///
+/// [`Sink::poll_ready`] waits for vacant space and fails with `()` if the consumer was dropped.
+/// [`Sink::poll_close`] closes the producer so that the consumer observes the end of the stream.
 impl<R: AsyncRbRef> Sink<<R::Rb as Observer>::Item> for AsyncProd<R> {
     type Error = ();
 
@@ -49,7 +51,7 @@ impl<R: AsyncRbRef> Sink<<R::Rb as Observer>::Item> for AsyncProd<R> {
         // Don't need to be flushed.
         Poll::Ready(Ok(()))
     }
-    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
         self.close();
         Poll::Ready(Ok(()))
     }
@@ -67,7 +69,7 @@ where
         // Don't need to be flushed.
         Poll::Ready(Ok(()))
     }
-    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         self.close();
         Poll::Ready(Ok(()))
     }
@@ -3,7 +3,14 @@ use crate::sync::StdSemaphore;
 use crate::{sync::Semaphore, BlockingCons, BlockingProd};
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use core::{
+    hint::spin_loop,
+    mem::MaybeUninit,
+    num::NonZeroUsize,
+    sync::atomic::{AtomicBool, Ordering},
+};
+#[cfg(feature = "notify")]
+use core::{mem, ptr, sync::atomic::AtomicPtr};
 #[cfg(feature = "alloc")]
 use ringbuf::traits::Split;
 use ringbuf::{
@@ -18,12 +25,30 @@ pub struct BlockingRb<S: Storage, X: Semaphore> {
     base: SharedRb<S>,
     pub(crate) read: X,
     pub(crate) write: X,
+    /// Don't give `write` semaphore on write index change.
+    pub(crate) write_quiet: AtomicBool,
+    /// Producer waits before changing write index while set.
+    pub(crate) write_locked: AtomicBool,
+    /// Producer or consumer has been explicitly closed.
+    closed: AtomicBool,
+    #[cfg(feature = "notify")]
+    /// Callback function pointer, null if not set.
+    notify: AtomicPtr<()>,
 }
 #[cfg(feature = "std")]
 pub struct BlockingRb<S: Storage, X: Semaphore = StdSemaphore> {
     base: SharedRb<S>,
     pub(crate) read: X,
     pub(crate) write: X,
+    /// Don't give `write` semaphore on write index change.
+    pub(crate) write_quiet: AtomicBool,
+    /// Producer waits before changing write index while set.
+    pub(crate) write_locked: AtomicBool,
+    /// Producer or consumer has been explicitly closed.
+    closed: AtomicBool,
+    #[cfg(feature = "notify")]
+    /// Callback function pointer, null if not set.
+    notify: AtomicPtr<()>,
 }
 
 impl<S: Storage, X: Semaphore> BlockingRb<S, X> {
@@ -32,6 +57,53 @@ impl<S: Storage, X: Semaphore> BlockingRb<S, X> {
             base,
             read: X::default(),
             write: X::default(),
+            write_quiet: AtomicBool::new(false),
+            write_locked: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
+            #[cfg(feature = "notify")]
+            notify: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Set callback that is called on every change of the ring buffer state.
+    ///
+    /// The callback is called after each push, pop and closing of the producer or the consumer,
+    /// so it can be used to wake external event loops. `None` removes the callback.
+    ///
+    /// It may be also set from an observer using `obs.rb().set_notify(..)`.
+    #[cfg(feature = "notify")]
+    pub fn set_notify(&self, f: Option<fn()>) {
+        self.notify.store(f.map_or(ptr::null_mut(), |f| f as *mut ()), Ordering::Release);
+    }
+
+    /// Mark the ring buffer as closed and wake both the producer and the consumer.
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.read.give();
+        self.write.give();
+        self.notify();
+    }
+    /// Whether the producer or the consumer has been explicitly closed.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Wake the consumer.
+    pub(crate) fn give_write(&self) {
+        self.write.give();
+        self.notify();
+    }
+
+    #[inline]
+    fn notify(&self) {
+        #[cfg(feature = "notify")]
+        {
+            let f = self.notify.load(Ordering::Acquire);
+            if !f.is_null() {
+                // Pointer has been obtained from `fn()`, so it keeps provenance of the function.
+                let f = unsafe { mem::transmute::<*mut (), fn()>(f) };
+                f();
+            }
         }
     }
 }
@@ -68,35 +140,71 @@ impl<S: Storage, X: Semaphore> Observer for BlockingRb<S, X> {
     fn write_is_held(&self) -> bool {
         self.base.write_is_held()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.base.has_been_full()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.base.clear_overflow_flag()
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.base.high_water_mark()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.base.reset_high_water_mark()
+    }
 }
 impl<S: Storage, X: Semaphore> Producer for BlockingRb<S, X> {
     unsafe fn set_write_index(&self, value: usize) {
+        while self.write_locked.load(Ordering::SeqCst) {
+            spin_loop();
+        }
         self.base.set_write_index(value);
-        self.write.give();
+        if !self.write_quiet.load(Ordering::Relaxed) {
+            self.give_write();
+        }
     }
 }
 impl<S: Storage, X: Semaphore> Consumer for BlockingRb<S, X> {
     unsafe fn set_read_index(&self, value: usize) {
         self.base.set_read_index(value);
         self.read.give();
+        self.notify();
     }
 }
 impl<S: Storage, X: Semaphore> RingBuffer for BlockingRb<S, X> {
     unsafe fn hold_read(&self, flag: bool) -> bool {
         let old = self.base.hold_read(flag);
         self.read.give();
+        self.notify();
         old
     }
     unsafe fn hold_write(&self, flag: bool) -> bool {
         let old = self.base.hold_write(flag);
         self.write.give();
+        self.notify();
         old
     }
 }
 
 impl<S: Storage, X: Semaphore> SplitRef for BlockingRb<S, X> {
-    type RefProd<'a> = BlockingProd<&'a Self> where Self: 'a;
-    type RefCons<'a> = BlockingCons<&'a Self> where Self: 'a;
+    type RefProd<'a>
+        = BlockingProd<&'a Self>
+    where
+        Self: 'a;
+    type RefCons<'a>
+        = BlockingCons<&'a Self>
+    where
+        Self: 'a;
 
     fn split_ref(&mut self) -> (Self::RefProd<'_>, Self::RefCons<'_>) {
         (BlockingProd::new(self), BlockingCons::new(self))
@@ -1,4 +1,9 @@
-use core::time::Duration;
+use core::{
+    hint::spin_loop,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 #[cfg(feature = "std")]
 use std::{
     mem::replace,
@@ -37,7 +42,7 @@ pub trait Semaphore: Default {
     /// + on timeout - `false`.
     fn take(&self, timeout: Option<Duration>) -> bool;
 
-    fn take_iter(&self, timeout: Option<Duration>) -> TakeIter<Self> {
+    fn take_iter(&self, timeout: Option<Duration>) -> TakeIter<'_, Self> {
         TakeIter {
             reset: false,
             semaphore: self,
@@ -59,6 +64,42 @@ impl Instant for StdInstant {
     }
 }
 
+/// Monotonic hardware tick counter used by [`TickInstant`].
+pub trait TickSource {
+    /// Number of ticks per second.
+    const FREQUENCY: u64;
+    /// Width of the counter in bits.
+    ///
+    /// Counter wraps to zero after reaching `2^BITS - 1`. Must be in range `1..=64`.
+    const BITS: u32 = u64::BITS;
+
+    /// Current value of the counter.
+    fn ticks() -> u64;
+}
+
+/// [`Instant`] that reads time from the `T` tick counter (e.g. a hardware timer).
+///
+/// Counter wraparound is handled correctly as long as measured interval is shorter than the counter period.
+pub struct TickInstant<T: TickSource> {
+    start: u64,
+    _ghost: PhantomData<fn() -> T>,
+}
+
+impl<T: TickSource> Instant for TickInstant<T> {
+    fn now() -> Self {
+        Self {
+            start: T::ticks(),
+            _ghost: PhantomData,
+        }
+    }
+    fn elapsed(&self) -> Duration {
+        let mask = u64::MAX >> (u64::BITS - T::BITS);
+        let ticks = T::ticks().wrapping_sub(self.start) & mask;
+        let nanos = (ticks % T::FREQUENCY) as u128 * 1_000_000_000 / T::FREQUENCY as u128;
+        Duration::new(ticks / T::FREQUENCY, nanos as u32)
+    }
+}
+
 #[cfg(feature = "std")]
 #[derive(Default)]
 pub struct StdSemaphore {
@@ -100,6 +141,87 @@ impl Semaphore for StdSemaphore {
     }
 }
 
+/// Semaphore based on [`parking_lot`] primitives.
+///
+/// Could be used instead of [`StdSemaphore`] as a faster alternative that doesn't poison.
+#[cfg(feature = "parking_lot")]
+#[derive(Default)]
+pub struct ParkingLotSemaphore {
+    condvar: parking_lot::Condvar,
+    mutex: parking_lot::Mutex<bool>,
+}
+
+#[cfg(feature = "parking_lot")]
+impl Semaphore for ParkingLotSemaphore {
+    type Instant = StdInstant;
+
+    fn give(&self) {
+        let mut guard = self.mutex.lock();
+        *guard = true;
+        self.condvar.notify_one();
+    }
+
+    fn try_take(&self) -> bool {
+        replace(&mut self.mutex.lock(), false)
+    }
+    fn take(&self, timeout: Option<Duration>) -> bool {
+        let mut guard = self.mutex.lock();
+        for timeout in TimeoutIter::<Self::Instant>::new(timeout) {
+            if replace(&mut guard, false) {
+                return true;
+            }
+            match timeout {
+                Some(t) => {
+                    if self.condvar.wait_for(&mut guard, t).timed_out() {
+                        break;
+                    }
+                }
+                None => self.condvar.wait(&mut guard),
+            };
+        }
+        replace(&mut guard, false)
+    }
+}
+
+/// Semaphore that busy-waits instead of blocking the thread.
+///
+/// Doesn't require `std`, so it could be used on bare-metal targets.
+/// Timeouts are measured using the user-supplied `I` clock.
+pub struct SpinSemaphore<I: Instant> {
+    flag: AtomicBool,
+    _ghost: PhantomData<fn() -> I>,
+}
+
+impl<I: Instant> Default for SpinSemaphore<I> {
+    fn default() -> Self {
+        Self {
+            flag: AtomicBool::new(false),
+            _ghost: PhantomData,
+        }
+    }
+}
+
+impl<I: Instant> Semaphore for SpinSemaphore<I> {
+    type Instant = I;
+
+    fn give(&self) {
+        self.flag.store(true, Ordering::Release);
+    }
+
+    fn try_take(&self) -> bool {
+        self.flag.swap(false, Ordering::AcqRel)
+    }
+    fn take(&self, timeout: Option<Duration>) -> bool {
+        for _ in TimeoutIter::<Self::Instant>::new(timeout) {
+            if self.try_take() {
+                return true;
+            }
+            spin_loop();
+        }
+        self.try_take()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TimeoutIter<I: Instant> {
     start: I,
@@ -194,3 +194,291 @@ fn write_read() {
 
     assert_eq!(*smsg, rmsg);
 }
+
+#[test]
+fn snapshot() {
+    let rb = BlockingHeapRb::<i32>::new(4);
+    let (mut prod, mut cons) = rb.split();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.push_slice(&[3, 4]), 2);
+
+    let mut items = vec![-1];
+    cons.snapshot_into(&mut items);
+    assert_eq!(items, [-1, 1, 2, 3, 4]);
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_locks_producer() {
+    struct Slow(u8);
+    impl Clone for Slow {
+        fn clone(&self) -> Self {
+            thread::sleep(Duration::from_millis(200));
+            Slow(self.0)
+        }
+    }
+
+    let rb = BlockingHeapRb::<Slow>::new(4);
+    let (mut prod, cons) = rb.split();
+    assert!(prod.try_push(Slow(0)).is_ok());
+
+    let cjh = thread::spawn(move || {
+        let mut items = Vec::new();
+        cons.snapshot_into(&mut items);
+        items.len()
+    });
+
+    thread::sleep(Duration::from_millis(50));
+    let start = std::time::Instant::now();
+    assert!(prod.try_push(Slow(1)).is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert_eq!(cjh.join().unwrap(), 1);
+}
+
+#[test]
+fn push_slice_transactional() {
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, mut cons) = rb.split();
+    prod.set_timeout(Some(Duration::from_millis(10)));
+
+    assert_eq!(prod.push_slice_transactional(&[0, 1, 2]), Ok(()));
+    assert_eq!(prod.push_slice_transactional(&[3, 4]), Err(WaitError::TimedOut));
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.push_slice_transactional(&[3, 4]), Ok(()));
+
+    drop(cons);
+    assert_eq!(prod.push_slice_transactional(&[5]), Err(WaitError::Closed));
+    assert_eq!(prod.occupied_len(), 4);
+}
+
+#[test]
+#[should_panic]
+fn push_slice_transactional_too_long() {
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, _cons) = rb.split();
+    prod.set_timeout(Some(Duration::from_millis(10)));
+
+    let _ = prod.push_slice_transactional(&[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn push_timeout() {
+    let rb = BlockingHeapRb::<u8>::new(1);
+    let (mut prod, cons) = rb.split();
+    prod.set_timeout(TIMEOUT);
+
+    assert_eq!(prod.push_timeout(0, Some(Duration::from_millis(10))), Ok(()));
+    assert_eq!(prod.push_timeout(1, Some(Duration::from_millis(10))), Err((WaitError::TimedOut, 1)));
+    assert_eq!(prod.timeout(), TIMEOUT);
+
+    drop(cons);
+    assert_eq!(prod.push_timeout(2, None), Err((WaitError::Closed, 2)));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn pop_slice_all() {
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, mut cons) = rb.split();
+    cons.set_timeout(Some(Duration::from_millis(100)));
+
+    let pjh = thread::spawn(move || {
+        prod.set_timeout(TIMEOUT);
+        for i in 0..6 {
+            prod.push(i).unwrap();
+        }
+        prod
+    });
+    let mut buf = [0; 6];
+    assert_eq!(cons.pop_slice_all(&mut buf), Ok(()));
+    assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+
+    let mut prod = pjh.join().unwrap();
+    prod.try_push(6).unwrap();
+    assert_eq!(cons.pop_slice_all(&mut buf), Err(1));
+    assert_eq!(buf[0], 6);
+
+    prod.try_push(7).unwrap();
+    drop(prod);
+    cons.set_timeout(None);
+    assert_eq!(cons.pop_slice_all(&mut buf), Err(1));
+    assert_eq!(buf[0], 7);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn close() {
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, mut cons) = rb.split();
+    let obs = prod.observe();
+
+    let cjh = thread::spawn(move || {
+        assert_eq!(cons.pop(), Ok(0));
+        assert_eq!(cons.pop(), Err(WaitError::Closed));
+    });
+
+    prod.set_timeout(TIMEOUT);
+    prod.push(0).unwrap();
+    prod.close();
+    cjh.join().unwrap();
+    assert!(!obs.write_is_held() && !obs.read_is_held());
+
+    let rb = BlockingHeapRb::<u8>::new(1);
+    let (mut prod, cons) = rb.split();
+    prod.push(0).unwrap();
+    assert!(!prod.is_closed());
+    cons.close();
+    assert!(prod.is_closed());
+    assert_eq!(prod.push(1), Err((WaitError::Closed, 1)));
+}
+
+#[cfg(feature = "notify")]
+#[test]
+fn notify() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    fn bump() {
+        COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, mut cons) = rb.split();
+    prod.set_notify(Some(bump));
+
+    prod.push(0).unwrap();
+    assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(cons.pop(), Ok(0));
+    assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+
+    cons.set_notify(None);
+    prod.push(1).unwrap();
+    assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+}
+
+#[cfg(feature = "parking_lot")]
+#[test]
+#[cfg_attr(miri, ignore)]
+fn parking_lot() {
+    use crate::sync::ParkingLotSemaphore;
+
+    let rb = BlockingHeapRb::<u8, ParkingLotSemaphore>::new(7);
+    let (mut prod, mut cons) = rb.split();
+
+    let smsg = Arc::new(THE_BOOK_FOREWORD.repeat(N_REP));
+
+    let pjh = thread::spawn({
+        let smsg = smsg.clone();
+        move || {
+            prod.set_timeout(TIMEOUT);
+            assert_eq!(prod.push_exact(&smsg), smsg.len());
+        }
+    });
+
+    let cjh = thread::spawn(move || {
+        cons.set_timeout(TIMEOUT);
+        cons.pop_all_iter().collect::<Vec<_>>()
+    });
+
+    pjh.join().unwrap();
+    let rmsg = cjh.join().unwrap();
+
+    assert_eq!(*smsg, rmsg);
+}
+
+#[test]
+fn push_batched_notify() {
+    use crate::sync::Semaphore;
+
+    let rb = BlockingHeapRb::<u8>::new(4);
+    let (mut prod, mut cons) = rb.split();
+    let rb = prod.rb.clone();
+    let write = &rb.write;
+    write.try_take();
+
+    assert_eq!(prod.push_batched_notify(0, 2), Ok(()));
+    assert!(!write.try_take());
+    assert_eq!(prod.push_batched_notify(1, 2), Ok(()));
+    assert!(write.try_take());
+
+    assert_eq!(prod.push_batched_notify(2, 3), Ok(()));
+    assert!(!write.try_take());
+    prod.flush_notify();
+    assert!(write.try_take());
+
+    // Consumer is notified when the buffer becomes full.
+    assert_eq!(prod.push_batched_notify(3, 3), Ok(()));
+    assert!(write.try_take());
+
+    cons.set_timeout(TIMEOUT);
+    assert!(cons.pop_all_iter().take(4).eq(0..4));
+
+    assert_eq!(prod.push_batched_notify(4, 3), Ok(()));
+    assert!(!write.try_take());
+    prod.flush().unwrap();
+    assert!(write.try_take());
+
+    assert_eq!(prod.push_batched_notify(5, 3), Ok(()));
+    assert!(!write.try_take());
+    drop(prod);
+    assert!(write.try_take());
+    assert!(cons.pop_all_iter().eq(4..6));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn spin_semaphore() {
+    use crate::sync::{SpinSemaphore, StdInstant};
+
+    let rb = BlockingHeapRb::<u8, SpinSemaphore<StdInstant>>::new(7);
+    let (mut prod, mut cons) = rb.split();
+    let msg = Vec::from_iter(0..64);
+
+    let pjh = thread::spawn({
+        let msg = msg.clone();
+        move || {
+            prod.set_timeout(TIMEOUT);
+            assert_eq!(prod.push_exact(&msg), msg.len());
+        }
+    });
+
+    cons.set_timeout(TIMEOUT);
+    assert_eq!(cons.pop_all_iter().collect::<Vec<_>>(), msg);
+    pjh.join().unwrap();
+
+    assert_eq!(cons.pop(), Err(WaitError::Closed));
+
+    let rb = BlockingHeapRb::<u8, SpinSemaphore<StdInstant>>::new(1);
+    let (_prod, mut cons) = rb.split();
+    cons.set_timeout(Some(Duration::from_millis(10)));
+    assert_eq!(cons.pop(), Err(WaitError::TimedOut));
+}
+
+#[test]
+fn tick_instant() {
+    use crate::sync::{Instant, TickInstant, TickSource};
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    static TICKS: AtomicU64 = AtomicU64::new(200);
+
+    struct Ticks;
+    impl TickSource for Ticks {
+        const FREQUENCY: u64 = 4;
+        const BITS: u32 = 8;
+        fn ticks() -> u64 {
+            TICKS.load(Ordering::Relaxed)
+        }
+    }
+
+    let start = TickInstant::<Ticks>::now();
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    TICKS.store(205, Ordering::Relaxed);
+    assert_eq!(start.elapsed(), Duration::from_millis(1250));
+
+    TICKS.store(10, Ordering::Relaxed);
+    assert_eq!(start.elapsed(), Duration::from_millis(16500));
+}
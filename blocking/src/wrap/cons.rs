@@ -1,5 +1,7 @@
 use super::{BlockingWrap, WaitError};
 use crate::{rb::BlockingRbRef, sync::Semaphore};
+#[cfg(feature = "alloc")]
+use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
 #[cfg(feature = "std")]
 use ringbuf::traits::Based;
@@ -22,13 +24,16 @@ macro_rules! wait_iter {
 }
 
 impl<R: BlockingRbRef> BlockingCons<R> {
+    /// Whether the corresponding producer was closed or dropped.
     pub fn is_closed(&self) -> bool {
-        !self.write_is_held()
+        self.rb.rb().is_closed() || !self.write_is_held()
     }
 
+    /// Set timeout for blocking operations, `None` means waiting forever.
     pub fn set_timeout(&mut self, timeout: Option<Duration>) {
         self.timeout = timeout;
     }
+    /// Timeout for blocking operations.
     pub fn timeout(&self) -> Option<Duration> {
         self.timeout
     }
@@ -61,6 +66,24 @@ impl<R: BlockingRbRef> BlockingCons<R> {
     pub fn pop_all_iter(&mut self) -> PopAllIter<'_, R> {
         PopAllIter { owner: self }
     }
+
+    /// Clones all items currently stored in the ring buffer to the end of `vec` without removing them.
+    ///
+    /// The producer is locked out while items are being cloned, so the snapshot is an exact point-in-time view
+    /// and no items are pushed in the meantime.
+    ///
+    /// *The producer spins if it tries to push while the snapshot is taken,
+    /// so this call causes a latency spike for the producer proportional to the number of cloned items.*
+    #[cfg(feature = "alloc")]
+    pub fn snapshot_into(&self, vec: &mut alloc::vec::Vec<<Self as Observer>::Item>)
+    where
+        <Self as Observer>::Item: Clone,
+    {
+        let _lock = WriteLock::new(&self.rb.rb().write_locked);
+        let (left, right) = self.base.as_slices();
+        vec.extend_from_slice(left);
+        vec.extend_from_slice(right);
+    }
 }
 
 impl<R: BlockingRbRef> BlockingCons<R>
@@ -84,6 +107,29 @@ where
         count
     }
 
+    /// Fill the whole `slice` with items waiting for them if needed.
+    ///
+    /// The timeout of the consumer limits the total waiting time, not the wait for each chunk of items.
+    ///
+    /// Returns `Err(count)` with the number of items been copied to `slice`
+    /// if the timeout is reached or the producer is closed and there are no more items.
+    pub fn pop_slice_all(&mut self, mut slice: &mut [<Self as Observer>::Item]) -> Result<(), usize> {
+        let mut count = 0;
+        for _ in wait_iter!(self) {
+            let n = self.base.pop_slice(slice);
+            slice = &mut slice[n..];
+            count += n;
+
+            if slice.is_empty() {
+                return Ok(());
+            }
+            if self.is_closed() && self.is_empty() {
+                break;
+            }
+        }
+        Err(count)
+    }
+
     #[cfg(feature = "alloc")]
     pub fn pop_until_end(&mut self, vec: &mut alloc::vec::Vec<<Self as Observer>::Item>) {
         if self.is_closed() && self.is_empty() {
@@ -137,3 +183,24 @@ impl<'a, R: BlockingRbRef> Iterator for PopAllIter<'a, R> {
         self.owner.pop().ok()
     }
 }
+
+/// Prevents the producer from changing write index until dropped.
+#[cfg(feature = "alloc")]
+struct WriteLock<'a> {
+    flag: &'a AtomicBool,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> WriteLock<'a> {
+    fn new(flag: &'a AtomicBool) -> Self {
+        flag.store(true, Ordering::SeqCst);
+        Self { flag }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Drop for WriteLock<'a> {
+    fn drop(&mut self) {
+        self.flag.store(false, Ordering::SeqCst);
+    }
+}
@@ -2,7 +2,7 @@ mod cons;
 mod prod;
 
 use crate::rb::BlockingRbRef;
-use core::time::Duration;
+use core::{mem::ManuallyDrop, ptr, time::Duration};
 use ringbuf::{
     traits::Based,
     wrap::{caching::Caching, Wrap},
@@ -13,26 +13,67 @@ pub struct BlockingWrap<R: BlockingRbRef, const P: bool, const C: bool> {
     pub(crate) rb: R,
     pub(crate) base: Caching<R, P, C>,
     pub(crate) timeout: Option<Duration>,
+    /// Number of pushed items the consumer wasn't notified about.
+    pub(crate) unnotified: usize,
+}
+
+impl<R: BlockingRbRef, const P: bool, const C: bool> Drop for BlockingWrap<R, P, C> {
+    fn drop(&mut self) {
+        self.notify_pending();
+    }
 }
 
 impl<R: BlockingRbRef, const P: bool, const C: bool> BlockingWrap<R, P, C> {
+    /// Create a new wrapper.
+    ///
+    /// Panics if wrapper with matching rights already exists.
     pub fn new(rb: R) -> Self {
         Self {
             rb: rb.clone(),
             base: Caching::new(rb),
             timeout: None,
+            unnotified: 0,
         }
     }
 
+    /// Get ring buffer observer.
     pub fn observe(&self) -> Obs<R> {
         self.base().observe()
     }
+
+    /// Set callback that is called on every change of the ring buffer state.
+    ///
+    /// See [`BlockingRb::set_notify`](crate::BlockingRb::set_notify).
+    #[cfg(feature = "notify")]
+    pub fn set_notify(&self, f: Option<fn()>) {
+        self.rb.rb().set_notify(f);
+    }
+
+    /// Wake the consumer if it wasn't notified about some pushed items.
+    pub(crate) fn notify_pending(&mut self) {
+        if self.unnotified > 0 {
+            self.unnotified = 0;
+            self.rb.rb().give_write();
+        }
+    }
+
+    /// Close the wrapper explicitly.
+    ///
+    /// Pending changes are committed, then the ring buffer is marked as closed and the opposite side is woken.
+    /// The opposite side observes the closure immediately regardless of when the ring buffer itself is dropped,
+    /// e.g. if other references to it (like observers) are still alive.
+    pub fn close(self) {
+        let rb = self.into_rb_ref();
+        rb.rb().close();
+    }
 }
 impl<R: BlockingRbRef, const P: bool, const C: bool> Based for BlockingWrap<R, P, C> {
     type Base = Caching<R, P, C>;
+    #[inline]
     fn base(&self) -> &Self::Base {
         &self.base
     }
+    #[inline]
     fn base_mut(&mut self) -> &mut Self::Base {
         &mut self.base
     }
@@ -42,8 +83,14 @@ impl<R: BlockingRbRef, const P: bool, const C: bool> Wrap for BlockingWrap<R, P,
     fn rb_ref(&self) -> &Self::RbRef {
         &self.rb
     }
-    fn into_rb_ref(self) -> Self::RbRef {
-        self.base.into_rb_ref()
+    fn into_rb_ref(mut self) -> Self::RbRef {
+        self.notify_pending();
+        let mut this = ManuallyDrop::new(self);
+        unsafe {
+            ptr::drop_in_place(&mut this.rb);
+            ptr::read(&this.base)
+        }
+        .into_rb_ref()
     }
 }
 
@@ -1,6 +1,6 @@
 use super::{BlockingWrap, WaitError};
 use crate::{rb::BlockingRbRef, sync::Semaphore};
-use core::time::Duration;
+use core::{mem, sync::atomic::Ordering, time::Duration};
 #[cfg(feature = "std")]
 use ringbuf::traits::Based;
 use ringbuf::{
@@ -22,13 +22,16 @@ macro_rules! wait_iter {
 }
 
 impl<R: BlockingRbRef> BlockingProd<R> {
+    /// Whether the corresponding consumer was closed or dropped.
     pub fn is_closed(&self) -> bool {
-        !self.read_is_held()
+        self.rb.rb().is_closed() || !self.read_is_held()
     }
 
+    /// Set timeout for blocking operations, `None` means waiting forever.
     pub fn set_timeout(&mut self, timeout: Option<Duration>) {
         self.timeout = timeout;
     }
+    /// Timeout for blocking operations.
     pub fn timeout(&self) -> Option<Duration> {
         self.timeout
     }
@@ -59,6 +62,54 @@ impl<R: BlockingRbRef> BlockingProd<R> {
         Err((WaitError::TimedOut, item))
     }
 
+    /// Push item waiting for a vacant slot no longer than `timeout` instead of the timeout set for the producer.
+    ///
+    /// On failure the item is returned back along with the reason:
+    /// + [`WaitError::TimedOut`] - there was no vacant slot until `timeout` expired,
+    /// + [`WaitError::Closed`] - the corresponding consumer was dropped.
+    pub fn push_timeout(
+        &mut self,
+        item: <Self as Observer>::Item,
+        timeout: Option<Duration>,
+    ) -> Result<(), (WaitError, <Self as Observer>::Item)> {
+        let saved = mem::replace(&mut self.timeout, timeout);
+        let result = self.push(item);
+        self.timeout = saved;
+        result
+    }
+
+    /// Push item notifying the consumer only once per `notify_every` pushed items.
+    ///
+    /// Coalesces consumer wakeups for fine-grained producers.
+    /// The consumer is also notified when the ring buffer becomes full, so it can always free some space.
+    ///
+    /// Remaining items are notified about by [`Self::flush_notify`], which is also called on `io::Write::flush`,
+    /// and when the producer is closed or dropped, so the consumer isn't left waiting for them.
+    pub fn push_batched_notify(
+        &mut self,
+        item: <Self as Observer>::Item,
+        notify_every: usize,
+    ) -> Result<(), (WaitError, <Self as Observer>::Item)> {
+        if self.base.is_full() {
+            self.flush_notify();
+        }
+        self.rb.rb().write_quiet.store(true, Ordering::Relaxed);
+        let result = self.push(item);
+        self.rb.rb().write_quiet.store(false, Ordering::Relaxed);
+        if result.is_ok() {
+            self.unnotified += 1;
+            if self.unnotified >= notify_every || self.base.is_full() {
+                self.flush_notify();
+            }
+        }
+        result
+    }
+
+    /// Notify the consumer about items pushed by [`Self::push_batched_notify`].
+    pub fn flush_notify(&mut self) {
+        self.notify_pending();
+    }
+
     pub fn push_all_iter<I: Iterator<Item = <Self as Observer>::Item>>(&mut self, iter: I) -> usize {
         let mut iter = iter.peekable();
         if iter.peek().is_none() {
@@ -104,6 +155,23 @@ where
         }
         count
     }
+
+    /// Push either the whole `slice` or nothing.
+    ///
+    /// Waits until there is enough vacant space for the whole slice.
+    /// If the consumer is closed or timeout is reached then nothing is written and error is returned.
+    ///
+    /// *Panics if length of `slice` is greater than ring buffer capacity.*
+    pub fn push_slice_transactional(&mut self, slice: &[<Self as Observer>::Item]) -> Result<(), WaitError> {
+        assert!(slice.len() <= self.capacity().get(), "slice is longer than ring buffer capacity");
+        self.wait_vacant(slice.len())?;
+        if self.is_closed() {
+            return Err(WaitError::Closed);
+        }
+        let n = self.base.push_slice(slice);
+        debug_assert_eq!(n, slice.len());
+        Ok(())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -124,6 +192,7 @@ where
         Err(io::ErrorKind::TimedOut.into())
     }
     fn flush(&mut self) -> io::Result<()> {
+        self.flush_notify();
         Ok(())
     }
 }
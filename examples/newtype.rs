@@ -0,0 +1,41 @@
+use ringbuf::{ring_buffer_newtype, traits::*, HeapCons, HeapProd, HeapRb};
+
+ring_buffer_newtype! {
+    /// Producer of input stream.
+    pub struct InputProd(HeapProd<u8>): Producer;
+}
+ring_buffer_newtype! {
+    /// Consumer of input stream.
+    pub struct InputCons(HeapCons<u8>): Consumer;
+}
+ring_buffer_newtype! {
+    /// Producer of output stream.
+    pub struct OutputProd(HeapProd<u8>): Producer;
+}
+ring_buffer_newtype! {
+    /// Consumer of output stream.
+    pub struct OutputCons(HeapCons<u8>): Consumer;
+}
+
+/// Converts input to uppercase.
+///
+/// Arguments cannot be swapped by mistake because they have different types.
+fn process(input: &mut InputCons, output: &mut OutputProd) {
+    while let Some(byte) = input.try_pop() {
+        output.try_push(byte.to_ascii_uppercase()).unwrap();
+    }
+}
+
+fn main() {
+    let (input_prod, input_cons) = HeapRb::<u8>::new(16).split();
+    let (output_prod, output_cons) = HeapRb::<u8>::new(16).split();
+    let (mut input_prod, mut input_cons) = (InputProd::from(input_prod), InputCons::from(input_cons));
+    let (mut output_prod, mut output_cons) = (OutputProd::from(output_prod), OutputCons::from(output_cons));
+
+    input_prod.push_slice(b"hello");
+    process(&mut input_cons, &mut output_prod);
+
+    let mut buf = [0; 5];
+    assert_eq!(output_cons.pop_slice(&mut buf), 5);
+    assert_eq!(&buf, b"HELLO");
+}
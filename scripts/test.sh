@@ -3,15 +3,36 @@
 cargo test && \
 cargo test --features test_local && \
 cargo test --features portable-atomic && \
+cargo test --features crc && \
+cargo test --features arrayvec && \
+cargo test --features serde && \
+cargo test --features memchr && \
+cargo test --features force-inline && \
+cargo test --features overflow-flag && \
+cargo test --features overflow-flag,test_local && \
+cargo test --features high-water-mark && \
+cargo test --features high-water-mark,test_local && \
+cargo test --features high-water-mark,portable-atomic && \
+cargo test --features no-cache-padding && \
+cargo check --no-default-features --features arrayvec && \
+cargo check --no-default-features --features serde && \
+cargo check --no-default-features --features memchr && \
 cargo check --no-default-features --features alloc && \
 cargo check --no-default-features && \
 cd async && \
 cargo test && \
+cargo test --features blocking-bridge && \
+cargo test --features overflow-flag && \
+cargo test --features high-water-mark && \
 cargo test --no-default-features --features alloc && \
 cargo check --no-default-features --features alloc && \
 cargo check --no-default-features && \
 cd ../blocking && \
 cargo test && \
+cargo test --features notify && \
+cargo test --features parking_lot && \
+cargo test --features overflow-flag && \
+cargo test --features high-water-mark && \
 cargo check --no-default-features --features alloc && \
 cargo check --no-default-features && \
 echo "Done!"
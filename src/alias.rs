@@ -10,6 +10,8 @@ use alloc::sync::Arc;
 
 /// Stack-allocated ring buffer with static capacity.
 ///
+/// Storage is placed inline, so it doesn't need `alloc`. Use [`SharedRb::new`](`SharedRb::<Array<T, N>>::new`) to create it in const context (e.g. for `static` items).
+///
 /// *Capacity (`N`) must be greater than zero.*
 pub type StaticRb<T, const N: usize> = SharedRb<Array<T, N>>;
 
@@ -1,4 +1,8 @@
-use crate::{storage::Array, traits::*, LocalRb, SharedRb};
+use crate::{
+    storage::{Array, Heap},
+    traits::*,
+    LocalRb, SharedRb,
+};
 use test::{black_box, Bencher};
 
 const RB_SIZE: usize = 256;
@@ -40,3 +44,33 @@ fn push_pop_x100(b: &mut Bencher) {
         }
     });
 }
+
+#[bench]
+fn push_pop_x100_pow2(b: &mut Bencher) {
+    let buf = SharedRb::<Heap<u64>>::with_capacity_pow2(RB_SIZE - 1);
+    let (mut prod, mut cons) = buf.split();
+    prod.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| {
+        for _ in 0..BATCH_SIZE {
+            prod.try_push(1).unwrap();
+        }
+        for _ in 0..BATCH_SIZE {
+            black_box(cons.try_pop().unwrap());
+        }
+    });
+}
+
+#[bench]
+fn push_pop_x100_non_pow2(b: &mut Bencher) {
+    let buf = SharedRb::<Heap<u64>>::new(RB_SIZE - 1);
+    let (mut prod, mut cons) = buf.split();
+    prod.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| {
+        for _ in 0..BATCH_SIZE {
+            prod.try_push(1).unwrap();
+        }
+        for _ in 0..BATCH_SIZE {
+            black_box(cons.try_pop().unwrap());
+        }
+    });
+}
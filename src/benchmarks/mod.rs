@@ -2,3 +2,4 @@ mod base;
 mod iter;
 mod parts;
 mod slice;
+mod throughput;
@@ -0,0 +1,153 @@
+//! Throughput of different ring buffer and wrapper types.
+//!
+//! Each benchmark sets `Bencher::bytes` to the number of items transferred per iteration,
+//! so reported `MB/s` should be read as millions of items per second.
+//!
+//! `*_single` benchmarks push and pop items one by one, run them with and without `force-inline` feature
+//! to see the effect of inlining through wrapper layers.
+
+use crate::{storage::Heap, traits::*, Cons, LocalRb, Prod, SharedRb};
+use std::{thread, vec};
+use test::{black_box, Bencher};
+
+const RB_SIZE: usize = 1024;
+const COUNT: usize = 0x10000;
+
+fn local<T: Copy + Default>(b: &mut Bencher, batch: usize) {
+    let rb = LocalRb::<Heap<T>>::new(RB_SIZE);
+    let (mut prod, mut cons) = rb.split();
+    let src = vec![T::default(); batch];
+    let mut dst = vec![T::default(); batch];
+    b.bytes = COUNT as u64;
+    b.iter(|| {
+        for _ in 0..(COUNT / batch) {
+            assert_eq!(prod.push_slice(&src), batch);
+            assert_eq!(cons.pop_slice(&mut dst), batch);
+        }
+        black_box(&mut dst);
+    });
+}
+
+fn single<P: Producer<Item = u64>, C: Consumer<Item = u64>>(b: &mut Bencher, prod: &mut P, cons: &mut C) {
+    b.bytes = COUNT as u64;
+    b.iter(|| {
+        for i in 0..(COUNT as u64) {
+            prod.try_push(i).unwrap();
+            black_box(cons.try_pop().unwrap());
+        }
+    });
+}
+
+fn spsc<P, C>(b: &mut Bencher, prod: &mut P, cons: &mut C, batch: usize)
+where
+    P: Producer + Send,
+    C: Consumer<Item = P::Item> + Send,
+    P::Item: Copy + Default + Send + Sync,
+{
+    let src = vec![P::Item::default(); batch];
+    let mut dst = vec![P::Item::default(); batch];
+    b.bytes = COUNT as u64;
+    b.iter(|| {
+        thread::scope(|s| {
+            s.spawn(|| {
+                let mut count = 0;
+                while count < COUNT {
+                    count += prod.push_slice(&src[..batch.min(COUNT - count)]);
+                }
+            });
+            let mut count = 0;
+            while count < COUNT {
+                count += cons.pop_slice(&mut dst);
+            }
+            black_box(&mut dst);
+        });
+    });
+}
+
+fn shared_caching<T: Copy + Default + Send + Sync>(b: &mut Bencher, batch: usize) {
+    let mut rb = SharedRb::<Heap<T>>::new(RB_SIZE);
+    let (mut prod, mut cons) = rb.split_ref();
+    spsc(b, &mut prod, &mut cons, batch);
+}
+
+fn shared_direct<T: Copy + Default + Send + Sync>(b: &mut Bencher, batch: usize) {
+    let rb = SharedRb::<Heap<T>>::new(RB_SIZE);
+    let (mut prod, mut cons) = (Prod::new(&rb), Cons::new(&rb));
+    spsc(b, &mut prod, &mut cons, batch);
+}
+
+#[bench]
+fn local_x1(b: &mut Bencher) {
+    local::<u64>(b, 1);
+}
+#[bench]
+fn local_x16(b: &mut Bencher) {
+    local::<u64>(b, 16);
+}
+#[bench]
+fn local_x256(b: &mut Bencher) {
+    local::<u64>(b, 256);
+}
+
+#[bench]
+fn shared_caching_x1(b: &mut Bencher) {
+    shared_caching::<u64>(b, 1);
+}
+#[bench]
+fn shared_caching_x16(b: &mut Bencher) {
+    shared_caching::<u64>(b, 16);
+}
+#[bench]
+fn shared_caching_x256(b: &mut Bencher) {
+    shared_caching::<u64>(b, 256);
+}
+
+#[bench]
+fn shared_direct_x1(b: &mut Bencher) {
+    shared_direct::<u64>(b, 1);
+}
+#[bench]
+fn shared_direct_x16(b: &mut Bencher) {
+    shared_direct::<u64>(b, 16);
+}
+#[bench]
+fn shared_direct_x256(b: &mut Bencher) {
+    shared_direct::<u64>(b, 256);
+}
+
+#[bench]
+fn local_zst_x16(b: &mut Bencher) {
+    local::<()>(b, 16);
+}
+#[bench]
+fn shared_caching_zst_x16(b: &mut Bencher) {
+    shared_caching::<()>(b, 16);
+}
+
+#[bench]
+fn local_large_x16(b: &mut Bencher) {
+    local::<[u64; 32]>(b, 16);
+}
+#[bench]
+fn shared_caching_large_x16(b: &mut Bencher) {
+    shared_caching::<[u64; 32]>(b, 16);
+}
+
+#[bench]
+fn local_single(b: &mut Bencher) {
+    let mut rb = LocalRb::<Heap<u64>>::new(RB_SIZE);
+    let (mut prod, mut cons) = rb.split_ref();
+    single(b, &mut prod, &mut cons);
+}
+#[bench]
+fn shared_caching_single(b: &mut Bencher) {
+    let mut rb = SharedRb::<Heap<u64>>::new(RB_SIZE);
+    let (mut prod, mut cons) = rb.split_ref();
+    single(b, &mut prod, &mut cons);
+}
+#[bench]
+fn shared_direct_single(b: &mut Bencher) {
+    let rb = SharedRb::<Heap<u64>>::new(RB_SIZE);
+    let (mut prod, mut cons) = (Prod::new(&rb), Cons::new(&rb));
+    single(b, &mut prod, &mut cons);
+}
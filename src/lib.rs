@@ -141,6 +141,9 @@ so to perform it concurrently you need to guard the ring buffer with mutex or so
 //! For example, we cannot store more than `capacity` items in the buffer,
 //! so `(write - read) % (2 * capacity)` is not allowed to be greater than `capacity`.
 //!
+//! Indices always stay below `2 * capacity` and capacity is limited to `usize::MAX / 4`,
+//! so index arithmetic never overflows no matter how many items have passed through the buffer.
+//!
 //! ## Hold flags
 //!
 //! Ring buffer can have at most one producer and at most one consumer at the same time.
@@ -157,8 +160,13 @@ extern crate std;
 
 /// Shortcuts for frequently used types.
 mod alias;
+/// Newtype wrappers.
+mod newtype;
 /// Ring buffer implementations.
 pub mod rb;
+/// Serde support.
+#[cfg(feature = "serde")]
+mod serialize;
 /// Storage types.
 pub mod storage;
 /// Ring buffer traits.
@@ -174,9 +182,9 @@ pub mod wrap;
 mod tests;
 
 pub use alias::*;
-pub use rb::{LocalRb, SharedRb};
+pub use rb::{BitRb, LocalRb, SharedRb};
 pub use traits::{consumer, producer};
-pub use transfer::transfer;
+pub use transfer::{transfer, transfer_all, transfer_exact, transfer_map};
 pub use wrap::{CachingCons, CachingProd, Cons, Obs, Prod};
 
 #[cfg(feature = "bench")]
@@ -0,0 +1,62 @@
+/// Generates a distinct newtype wrapper around a ring buffer producer, consumer or observer.
+///
+/// All methods of the corresponding trait are delegated to the wrapped value at zero cost,
+/// while the new type cannot be accidentally mixed up with other wrappers of the same inner type.
+///
+/// Role after colon may be `Producer`, `Consumer` or `Observer`.
+///
+/// ```
+/// # extern crate ringbuf;
+/// use ringbuf::{ring_buffer_newtype, traits::*, HeapCons, HeapProd, HeapRb};
+///
+/// ring_buffer_newtype! {
+///     /// Producer of requests.
+///     pub struct RequestProd(HeapProd<u8>): Producer;
+/// }
+/// ring_buffer_newtype! {
+///     /// Consumer of requests.
+///     pub struct RequestCons(HeapCons<u8>): Consumer;
+/// }
+///
+/// # fn main() {
+/// let (prod, cons) = HeapRb::<u8>::new(4).split();
+/// let (mut prod, mut cons) = (RequestProd::from(prod), RequestCons::from(cons));
+///
+/// assert_eq!(prod.push_slice(&[1, 2]), 2);
+/// assert_eq!(cons.try_pop(), Some(1));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! ring_buffer_newtype {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty): Producer;) => {
+        $crate::ring_buffer_newtype!($(#[$meta])* $vis struct $name($inner): Observer;);
+        impl $crate::traits::producer::DelegateProducer for $name {}
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty): Consumer;) => {
+        $crate::ring_buffer_newtype!($(#[$meta])* $vis struct $name($inner): Observer;);
+        impl $crate::traits::consumer::DelegateConsumer for $name {}
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty): Observer;) => {
+        $(#[$meta])*
+        $vis struct $name(pub $inner);
+
+        impl $crate::traits::Based for $name {
+            type Base = $inner;
+            #[inline]
+            fn base(&self) -> &Self::Base {
+                &self.0
+            }
+            #[inline]
+            fn base_mut(&mut self) -> &mut Self::Base {
+                &mut self.0
+            }
+        }
+        impl $crate::traits::observer::DelegateObserver for $name {}
+
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+    };
+}
@@ -0,0 +1,147 @@
+#[cfg(feature = "alloc")]
+use crate::storage::Heap;
+use crate::storage::{Array, Storage};
+use core::{mem::MaybeUninit, num::NonZeroUsize};
+
+const BITS: usize = usize::BITS as usize;
+
+/// Ring buffer of bits packed into words of underlying storage.
+///
+/// Uses single bit of memory per stored `bool` instead of a whole byte.
+///
+/// Unlike other ring buffers items are not addressable by reference, so it cannot be split and doesn't implement ring buffer traits.
+pub struct BitRb<S: Storage<Item = usize> + ?Sized> {
+    capacity: NonZeroUsize,
+    read: usize,
+    write: usize,
+    storage: S,
+}
+
+impl<S: Storage<Item = usize>> BitRb<S> {
+    /// Constructs an empty ring buffer of `capacity` bits from storage.
+    ///
+    /// *Panics if `capacity` is zero or greater than number of bits in storage.*
+    pub fn from_storage(storage: S, capacity: usize) -> Self {
+        assert!(capacity <= storage.len() * BITS);
+        for word in unsafe { storage.slice_mut(0..storage.len()) } {
+            *word = MaybeUninit::new(0);
+        }
+        Self {
+            capacity: NonZeroUsize::new(capacity).unwrap(),
+            read: 0,
+            write: 0,
+            storage,
+        }
+    }
+    /// Destructures ring buffer into underlying storage.
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+}
+
+impl<const N: usize> Default for BitRb<Array<usize, N>> {
+    fn default() -> Self {
+        Self::from_storage(crate::utils::uninit_array().into(), N * BITS)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BitRb<Heap<usize>> {
+    /// Creates a new ring buffer that can hold `capacity` bits.
+    ///
+    /// *Panics if allocation failed or `capacity` is zero.*
+    pub fn new(capacity: usize) -> Self {
+        Self::from_storage(Heap::new(capacity.div_ceil(BITS)), capacity)
+    }
+}
+
+impl<S: Storage<Item = usize> + ?Sized> BitRb<S> {
+    /// Capacity of the ring buffer in bits.
+    #[inline]
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+    /// Number of bits stored in the ring buffer.
+    pub fn occupied_len(&self) -> usize {
+        let modulus = 2 * self.capacity.get();
+        (modulus + self.write - self.read) % modulus
+    }
+    /// Number of bits that can be pushed into the ring buffer.
+    pub fn vacant_len(&self) -> usize {
+        self.capacity.get() - self.occupied_len()
+    }
+    /// Checks if the ring buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.read == self.write
+    }
+    /// Checks if the ring buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.vacant_len() == 0
+    }
+
+    /// Returns word and mask of a bit at `index`.
+    fn locate(&mut self, index: usize) -> (&mut usize, usize) {
+        let pos = index % self.capacity;
+        let word = unsafe { self.storage.slice_mut((pos / BITS)..(pos / BITS + 1)) };
+        (unsafe { word[0].assume_init_mut() }, 1 << (pos % BITS))
+    }
+
+    /// Appends a bit to the ring buffer.
+    ///
+    /// If buffer is full returns an `Err` containing the bit that hasn't been appended.
+    pub fn push_bit(&mut self, bit: bool) -> Result<(), bool> {
+        if self.is_full() {
+            return Err(bit);
+        }
+        let (word, mask) = self.locate(self.write);
+        if bit {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+        self.write = (self.write + 1) % (2 * self.capacity.get());
+        Ok(())
+    }
+    /// Removes the eldest bit from the ring buffer and returns it.
+    ///
+    /// Returns `None` if the ring buffer is empty.
+    pub fn pop_bit(&mut self) -> Option<bool> {
+        if self.is_empty() {
+            return None;
+        }
+        let (word, mask) = self.locate(self.read);
+        let bit = *word & mask != 0;
+        self.read = (self.read + 1) % (2 * self.capacity.get());
+        Some(bit)
+    }
+
+    /// Appends bits from slice to the ring buffer.
+    ///
+    /// Returns count of bits been appended to the ring buffer.
+    pub fn push_bits(&mut self, bits: &[bool]) -> usize {
+        let count = usize::min(bits.len(), self.vacant_len());
+        for &bit in &bits[..count] {
+            self.push_bit(bit).unwrap();
+        }
+        count
+    }
+    /// Removes the eldest bits from the ring buffer and writes them into a slice.
+    ///
+    /// Returns count of bits been removed from the ring buffer.
+    pub fn pop_bits(&mut self, bits: &mut [bool]) -> usize {
+        let count = usize::min(bits.len(), self.occupied_len());
+        for bit in &mut bits[..count] {
+            *bit = self.pop_bit().unwrap();
+        }
+        count
+    }
+
+    /// Removes all bits from the ring buffer.
+    ///
+    /// Returns the number of removed bits.
+    pub fn clear(&mut self) -> usize {
+        let count = self.occupied_len();
+        self.read = self.write;
+        count
+    }
+}
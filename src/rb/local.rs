@@ -1,4 +1,8 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::rb_impl_init,
+    utils::{ranges, MAX_CAPACITY},
+    SharedRb,
+};
 #[cfg(feature = "alloc")]
 use crate::traits::Split;
 use crate::{
@@ -8,12 +12,14 @@ use crate::{
         producer::{impl_producer_traits, Producer},
         Observer, RingBuffer, SplitRef,
     },
+    utils::debug_observer,
     wrap::{Cons, Prod},
 };
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, rc::Rc};
 use core::{
     cell::Cell,
+    fmt,
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
     ptr,
@@ -39,6 +45,10 @@ impl Endpoint {
 pub struct LocalRb<S: Storage + ?Sized> {
     read: Endpoint,
     write: Endpoint,
+    #[cfg(feature = "overflow-flag")]
+    been_full: Cell<bool>,
+    #[cfg(feature = "high-water-mark")]
+    high_water_mark: Cell<usize>,
     storage: S,
 }
 
@@ -49,13 +59,25 @@ impl<S: Storage> LocalRb<S> {
     ///
     /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
     /// `read` and `write` positions must be valid (see implementation details).
+    ///
+    /// *Panics if storage is empty or its length is greater than `usize::MAX / 4`.*
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         assert!(!storage.is_empty());
-        Self {
+        assert!(storage.len() <= MAX_CAPACITY);
+        let this = Self {
             storage,
             read: Endpoint::new(read),
             write: Endpoint::new(write),
-        }
+            #[cfg(feature = "overflow-flag")]
+            been_full: Cell::new(false),
+            #[cfg(feature = "high-water-mark")]
+            high_water_mark: Cell::new(0),
+        };
+        #[cfg(feature = "overflow-flag")]
+        this.been_full.set(this.is_full());
+        #[cfg(feature = "high-water-mark")]
+        this.high_water_mark.set(this.occupied_len());
+        this
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
     ///
@@ -66,6 +88,23 @@ impl<S: Storage> LocalRb<S> {
         let this = ManuallyDrop::new(self);
         (ptr::read(&this.storage), this.read_index(), this.write_index())
     }
+
+    /// Converts the ring buffer into a [`SharedRb`] that can be split between threads.
+    ///
+    /// Storage is moved as is, so the contents and their order are preserved.
+    pub fn into_shared(self) -> SharedRb<S> {
+        unsafe {
+            let (storage, read, write) = self.into_raw_parts();
+            SharedRb::from_raw_parts(storage, read, write)
+        }
+    }
+}
+
+/// Shows the ring buffer state but not its items.
+impl<S: Storage + ?Sized> fmt::Debug for LocalRb<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_observer(self, "LocalRb", f)
+    }
 }
 
 impl<S: Storage + ?Sized> Observer for LocalRb<S> {
@@ -102,17 +141,48 @@ impl<S: Storage + ?Sized> Observer for LocalRb<S> {
     fn write_is_held(&self) -> bool {
         self.write.held.get()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.been_full.get()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.been_full.set(false);
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.high_water_mark.get()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.high_water_mark.set(self.occupied_len());
+    }
 }
 
 impl<S: Storage + ?Sized> Producer for LocalRb<S> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_write_index(&self, value: usize) {
         self.write.index.set(value);
+        #[cfg(feature = "overflow-flag")]
+        if self.is_full() {
+            self.been_full.set(true);
+        }
+        #[cfg(feature = "high-water-mark")]
+        self.high_water_mark
+            .set(usize::max(self.high_water_mark.get(), self.occupied_len()));
     }
 }
 
 impl<S: Storage + ?Sized> Consumer for LocalRb<S> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_read_index(&self, value: usize) {
         self.read.index.set(value);
     }
@@ -13,6 +13,18 @@ macro_rules! rb_impl_init {
             }
         }
 
+        /// Creates an empty ring buffer over externally owned memory (e.g. a `static` buffer placed in a specific memory section).
+        ///
+        /// Capacity is equal to the slice length.
+        /// Remaining items are dropped along with the ring buffer but the memory itself is left untouched.
+        ///
+        /// *Panics if slice is empty or its length is greater than `usize::MAX / 4`.*
+        impl<'a, T> From<&'a mut [core::mem::MaybeUninit<T>]> for $type<crate::storage::Ref<'a, T>> {
+            fn from(value: &'a mut [core::mem::MaybeUninit<T>]) -> Self {
+                unsafe { Self::from_raw_parts(value.into(), usize::default(), usize::default()) }
+            }
+        }
+
         #[cfg(feature = "alloc")]
         impl<T> $type<crate::storage::Heap<T>> {
             /// Creates a new instance of a ring buffer.
@@ -21,6 +33,25 @@ macro_rules! rb_impl_init {
             pub fn new(capacity: usize) -> Self {
                 unsafe { Self::from_raw_parts(crate::storage::Heap::<T>::new(capacity), usize::default(), usize::default()) }
             }
+            /// Creates a new instance of a ring buffer with capacity rounded up to the next power of two.
+            ///
+            /// Index arithmetic of such ring buffer uses bit masks instead of division (see [`Observer::capacity_is_pow2`](`crate::traits::Observer::capacity_is_pow2`)).
+            /// Actual capacity is returned by [`Observer::capacity`](`crate::traits::Observer::capacity`).
+            ///
+            /// *Panics if allocation failed or rounded capacity overflows `usize`.*
+            pub fn with_capacity_pow2(min_capacity: usize) -> Self {
+                Self::new(min_capacity.checked_next_power_of_two().expect("capacity overflow"))
+            }
+            /// Creates a new instance of a ring buffer filled with items from `iter`.
+            ///
+            /// Only first `capacity` items are taken from `iter`, the rest remain in it.
+            ///
+            /// *Panics if allocation failed or `capacity` is zero.*
+            pub fn with_capacity_filled<I: Iterator<Item = T>>(capacity: usize, iter: I) -> Self {
+                let mut this = Self::new(capacity);
+                crate::traits::Producer::push_iter(&mut this, iter);
+                this
+            }
             /// Creates a new instance of a ring buffer returning an error if allocation failed.
             ///
             /// *Panics if `capacity` is zero.*
@@ -30,6 +61,42 @@ macro_rules! rb_impl_init {
                 unsafe { vec.set_len(capacity) };
                 Ok(unsafe { Self::from_raw_parts(vec.into_boxed_slice().into(), usize::default(), usize::default()) })
             }
+            /// Creates an empty ring buffer taking ownership of `boxed` uninitialized memory as storage.
+            ///
+            /// Capacity is equal to the slice length. The memory is freed when the ring buffer is dropped.
+            ///
+            /// *Panics if `boxed` is empty.*
+            pub fn from_boxed_slice(boxed: alloc::boxed::Box<[core::mem::MaybeUninit<T>]>) -> Self {
+                unsafe { Self::from_raw_parts(boxed.into(), usize::default(), usize::default()) }
+            }
+            /// Changes capacity of the ring buffer moving its items into newly allocated storage.
+            ///
+            /// Items order is preserved, the eldest item is placed at the beginning of the new storage.
+            ///
+            /// If the items don't fit into `capacity` then the ring buffer is left unchanged
+            /// and `Err` containing number of items in the ring buffer is returned.
+            ///
+            /// *Panics if allocation failed.*
+            pub fn resize(&mut self, capacity: core::num::NonZeroUsize) -> Result<(), usize> {
+                use crate::{storage::Storage, traits::Consumer};
+                let count = self.occupied_len();
+                if count > capacity.get() {
+                    return Err(count);
+                }
+                let storage = crate::storage::Heap::<T>::new(capacity.get());
+                assert_eq!(self.pop_slice_uninit(unsafe { storage.slice_mut(0..count) }), count);
+                drop(core::mem::replace(self, unsafe { Self::from_raw_parts(storage, 0, count) }));
+                Ok(())
+            }
+        }
+
+        /// Creates a ring buffer with serialized capacity and pushes serialized items into it.
+        #[cfg(all(feature = "alloc", feature = "serde"))]
+        impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for $type<crate::storage::Heap<T>> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let (storage, count) = crate::serialize::deserialize(deserializer)?;
+                Ok(unsafe { Self::from_raw_parts(storage.into(), 0, count) })
+            }
         }
 
         #[cfg(feature = "alloc")]
@@ -40,6 +107,16 @@ macro_rules! rb_impl_init {
             }
         }
 
+        /// Collects items into a full ring buffer with capacity equal to the number of items.
+        ///
+        /// *Panics if the iterator is empty.*
+        #[cfg(feature = "alloc")]
+        impl<T> core::iter::FromIterator<T> for $type<crate::storage::Heap<T>> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                Self::from(iter.into_iter().collect::<alloc::boxed::Box<[T]>>())
+            }
+        }
+
         #[cfg(feature = "alloc")]
         impl<T> From<alloc::boxed::Box<[T]>> for $type<crate::storage::Heap<T>> {
             fn from(value: alloc::boxed::Box<[T]>) -> Self {
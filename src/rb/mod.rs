@@ -1,11 +1,14 @@
+/// Bit-packed ring buffer implementation.
+pub mod bit;
 /// Single-threaded ring buffer implementation.
 pub mod local;
 mod macros;
 /// Multi-threaded ring buffer implementation.
 pub mod shared;
 mod traits;
-mod utils;
+pub(crate) mod utils;
 
+pub use bit::BitRb;
 pub use local::LocalRb;
-pub use shared::SharedRb;
+pub use shared::{Notify, SharedRb};
 pub use traits::*;
@@ -1,13 +1,17 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::rb_impl_init,
+    utils::{ranges, MAX_CAPACITY},
+};
 #[cfg(feature = "alloc")]
 use crate::traits::Split;
 use crate::{
-    storage::Storage,
+    storage::{Array, Owning, Storage},
     traits::{
         consumer::{impl_consumer_traits, Consumer},
         producer::{impl_producer_traits, Producer},
         Observer, RingBuffer, SplitRef,
     },
+    utils::debug_observer,
     wrap::{CachingCons, CachingProd},
 };
 #[cfg(feature = "alloc")]
@@ -15,16 +19,69 @@ use alloc::{boxed::Box, sync::Arc};
 #[cfg(not(feature = "portable-atomic"))]
 use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use core::{
+    fmt,
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
     ptr,
 };
+#[cfg(not(feature = "no-cache-padding"))]
 use crossbeam_utils::CachePadded;
 #[cfg(feature = "portable-atomic")]
 use portable_atomic::{AtomicBool, AtomicUsize, Ordering};
 
+/// Index wrapper that doesn't add any padding.
+#[cfg(feature = "no-cache-padding")]
+struct CachePadded<T>(T);
+
+#[cfg(feature = "no-cache-padding")]
+impl<T> CachePadded<T> {
+    const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "no-cache-padding")]
+impl<T> core::ops::Deref for CachePadded<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Hooks called by [`SharedRb`] after its indices are changed.
+///
+/// May be used to wake up the opposite side of the ring buffer by some external mechanism (e.g. an event loop).
+/// Hooks are called after the new index is stored, so the changes are already visible to the opposite side.
+/// Storing the same index again (e.g. on sync of a wrapper without changes) doesn't call hooks.
+/// *Hence changes by a multiple of `2 * capacity` items between two stores are not notified too.*
+///
+/// Wrappers that postpone index updates (e.g. frozen or caching without auto sync) trigger hooks only on commit.
+///
+/// `()` is the default no-op notifier.
+pub trait Notify {
+    /// Called after items have been pushed to the ring buffer.
+    #[inline]
+    fn on_push(&self) {}
+    /// Called after items have been removed from the ring buffer.
+    #[inline]
+    fn on_pop(&self) {}
+}
+
+impl Notify for () {}
+
 /// Ring buffer that can be shared between threads.
 ///
+/// Read and write indices are placed in separate cache lines to avoid false sharing between producer and consumer.
+/// Padding can be disabled by `no-cache-padding` feature to reduce memory usage.
+///
+/// Indices are stored with `Release` and loaded with `Acquire` ordering without any additional fences.
+/// This is the weakest ordering that guarantees that items written by the producer are visible to the consumer
+/// (and vice versa for the vacant space), so it isn't configurable.
+/// To reduce the number of index accesses use caching wrappers (see [`CachingProd::set_auto_sync`]) that load the opposite index
+/// only when there is not enough items or space and may postpone storing their own one.
+///
+/// Custom wakeup mechanism may be attached using [`Notify`] (see [`Self::with_notify`]).
+///
 /// Note that there is no explicit requirement of `T: Send`. Instead ring buffer will work just fine even with `T: !Send`
 /// until you try to send its producer or consumer to another thread.
 #[cfg_attr(
@@ -47,11 +104,16 @@ thread::spawn(move || {
 ```
 "##
 )]
-pub struct SharedRb<S: Storage + ?Sized> {
+pub struct SharedRb<S: Storage + ?Sized, N: Notify = ()> {
     read_index: CachePadded<AtomicUsize>,
     write_index: CachePadded<AtomicUsize>,
     read_held: AtomicBool,
     write_held: AtomicBool,
+    #[cfg(feature = "overflow-flag")]
+    been_full: AtomicBool,
+    #[cfg(feature = "high-water-mark")]
+    high_water_mark: AtomicUsize,
+    notify: N,
     storage: S,
 }
 
@@ -62,15 +124,28 @@ impl<S: Storage> SharedRb<S> {
     ///
     /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
     /// `read` and `write` positions must be valid (see implementation details).
+    ///
+    /// *Panics if storage is empty or its length is greater than `usize::MAX / 4`.*
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         assert!(!storage.is_empty());
-        Self {
+        assert!(storage.len() <= MAX_CAPACITY);
+        let this = Self {
             storage,
             read_index: CachePadded::new(AtomicUsize::new(read)),
             write_index: CachePadded::new(AtomicUsize::new(write)),
             read_held: AtomicBool::new(false),
             write_held: AtomicBool::new(false),
-        }
+            #[cfg(feature = "overflow-flag")]
+            been_full: AtomicBool::new(false),
+            #[cfg(feature = "high-water-mark")]
+            high_water_mark: AtomicUsize::new(0),
+            notify: (),
+        };
+        #[cfg(feature = "overflow-flag")]
+        this.been_full.store(this.is_full(), Ordering::Relaxed);
+        #[cfg(feature = "high-water-mark")]
+        this.high_water_mark.store(this.occupied_len(), Ordering::Relaxed);
+        this
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
     ///
@@ -83,7 +158,67 @@ impl<S: Storage> SharedRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Observer for SharedRb<S> {
+impl<S: Storage, N: Notify> SharedRb<S, N> {
+    /// Replaces notifier of the ring buffer, the previous one is dropped.
+    ///
+    /// Contents of the ring buffer are preserved.
+    pub fn with_notify<M: Notify>(self, notify: M) -> SharedRb<S, M> {
+        let this = ManuallyDrop::new(self);
+        drop(unsafe { ptr::read(&this.notify) });
+        SharedRb {
+            storage: unsafe { ptr::read(&this.storage) },
+            read_index: CachePadded::new(AtomicUsize::new(this.read_index())),
+            write_index: CachePadded::new(AtomicUsize::new(this.write_index())),
+            read_held: AtomicBool::new(this.read_is_held()),
+            write_held: AtomicBool::new(this.write_is_held()),
+            #[cfg(feature = "overflow-flag")]
+            been_full: AtomicBool::new(this.has_been_full()),
+            #[cfg(feature = "high-water-mark")]
+            high_water_mark: AtomicUsize::new(this.high_water_mark()),
+            notify,
+        }
+    }
+}
+
+impl<S: Storage + ?Sized, N: Notify> SharedRb<S, N> {
+    /// Notifier of the ring buffer.
+    pub fn notify(&self) -> &N {
+        &self.notify
+    }
+}
+
+impl<T, const N: usize> SharedRb<Array<T, N>> {
+    /// Creates an empty ring buffer with the storage placed inline.
+    ///
+    /// Doesn't require `alloc` and can be used to initialize `static` items.
+    ///
+    /// *Panics if `N` is zero or greater than `usize::MAX / 4`.*
+    pub const fn new() -> Self {
+        assert!(N > 0);
+        assert!(N <= MAX_CAPACITY);
+        Self {
+            storage: Owning::new(unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }),
+            read_index: CachePadded::new(AtomicUsize::new(0)),
+            write_index: CachePadded::new(AtomicUsize::new(0)),
+            read_held: AtomicBool::new(false),
+            write_held: AtomicBool::new(false),
+            #[cfg(feature = "overflow-flag")]
+            been_full: AtomicBool::new(false),
+            #[cfg(feature = "high-water-mark")]
+            high_water_mark: AtomicUsize::new(0),
+            notify: (),
+        }
+    }
+}
+
+/// Shows the ring buffer state but not its items.
+impl<S: Storage + ?Sized, N: Notify> fmt::Debug for SharedRb<S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_observer(self, "SharedRb", f)
+    }
+}
+
+impl<S: Storage + ?Sized, N: Notify> Observer for SharedRb<S, N> {
     type Item = S::Item;
 
     #[inline]
@@ -117,23 +252,63 @@ impl<S: Storage + ?Sized> Observer for SharedRb<S> {
     fn write_is_held(&self) -> bool {
         self.write_held.load(Ordering::Acquire)
     }
-}
 
-impl<S: Storage + ?Sized> Producer for SharedRb<S> {
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.been_full.load(Ordering::Relaxed)
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.been_full.store(false, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "high-water-mark")]
     #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.high_water_mark.load(Ordering::Relaxed)
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.high_water_mark.store(self.occupied_len(), Ordering::Relaxed);
+    }
+}
+
+impl<S: Storage + ?Sized, N: Notify> Producer for SharedRb<S, N> {
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_write_index(&self, value: usize) {
+        // Only the producer stores the write index, so relaxed load returns its own latest value.
+        let changed = self.write_index.load(Ordering::Relaxed) != value;
         self.write_index.store(value, Ordering::Release);
+        if changed {
+            self.notify.on_push();
+        }
+        #[cfg(feature = "overflow-flag")]
+        if self.is_full() {
+            self.been_full.store(true, Ordering::Relaxed);
+        }
+        #[cfg(feature = "high-water-mark")]
+        self.high_water_mark.fetch_max(self.occupied_len(), Ordering::Relaxed);
     }
 }
 
-impl<S: Storage + ?Sized> Consumer for SharedRb<S> {
-    #[inline]
+impl<S: Storage + ?Sized, N: Notify> Consumer for SharedRb<S, N> {
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_read_index(&self, value: usize) {
+        // Only the consumer stores the read index, so relaxed load returns its own latest value.
+        let changed = self.read_index.load(Ordering::Relaxed) != value;
         self.read_index.store(value, Ordering::Release);
+        if changed {
+            self.notify.on_pop();
+        }
     }
 }
 
-impl<S: Storage + ?Sized> RingBuffer for SharedRb<S> {
+impl<S: Storage + ?Sized, N: Notify> RingBuffer for SharedRb<S, N> {
     #[inline]
     unsafe fn hold_read(&self, flag: bool) -> bool {
         self.read_held.swap(flag, Ordering::AcqRel)
@@ -144,14 +319,17 @@ impl<S: Storage + ?Sized> RingBuffer for SharedRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Drop for SharedRb<S> {
+impl<S: Storage + ?Sized, N: Notify> Drop for SharedRb<S, N> {
     fn drop(&mut self) {
         self.clear();
     }
 }
 
+/// Splits the ring buffer into caching producer and consumer.
+///
+/// Caches of both halves are initialized with the current indices of the ring buffer.
 #[cfg(feature = "alloc")]
-impl<S: Storage> Split for SharedRb<S> {
+impl<S: Storage, N: Notify> Split for SharedRb<S, N> {
     type Prod = CachingProd<Arc<Self>>;
     type Cons = CachingCons<Arc<Self>>;
 
@@ -159,8 +337,11 @@ impl<S: Storage> Split for SharedRb<S> {
         Arc::new(self).split()
     }
 }
+/// Splits already shared ring buffer into caching producer and consumer.
+///
+/// *Panics if the producer or the consumer already exists.*
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Arc<SharedRb<S>> {
+impl<S: Storage + ?Sized, N: Notify> Split for Arc<SharedRb<S, N>> {
     type Prod = CachingProd<Self>;
     type Cons = CachingCons<Self>;
 
@@ -169,15 +350,15 @@ impl<S: Storage + ?Sized> Split for Arc<SharedRb<S>> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Box<SharedRb<S>> {
-    type Prod = CachingProd<Arc<SharedRb<S>>>;
-    type Cons = CachingCons<Arc<SharedRb<S>>>;
+impl<S: Storage + ?Sized, N: Notify> Split for Box<SharedRb<S, N>> {
+    type Prod = CachingProd<Arc<SharedRb<S, N>>>;
+    type Cons = CachingCons<Arc<SharedRb<S, N>>>;
 
     fn split(self) -> (Self::Prod, Self::Cons) {
-        Arc::<SharedRb<S>>::from(self).split()
+        Arc::<SharedRb<S, N>>::from(self).split()
     }
 }
-impl<S: Storage + ?Sized> SplitRef for SharedRb<S> {
+impl<S: Storage + ?Sized, N: Notify> SplitRef for SharedRb<S, N> {
     type RefProd<'a> = CachingProd<&'a Self> where Self: 'a;
     type RefCons<'a> = CachingCons<&'a Self> where Self: 'a;
 
@@ -188,15 +369,15 @@ impl<S: Storage + ?Sized> SplitRef for SharedRb<S> {
 
 rb_impl_init!(SharedRb);
 
-impl_producer_traits!(SharedRb<S: Storage>);
-impl_consumer_traits!(SharedRb<S: Storage>);
+impl_producer_traits!(SharedRb<S: Storage, N: Notify>);
+impl_consumer_traits!(SharedRb<S: Storage, N: Notify>);
 
-impl<S: Storage + ?Sized> AsRef<Self> for SharedRb<S> {
+impl<S: Storage + ?Sized, N: Notify> AsRef<Self> for SharedRb<S, N> {
     fn as_ref(&self) -> &Self {
         self
     }
 }
-impl<S: Storage + ?Sized> AsMut<Self> for SharedRb<S> {
+impl<S: Storage + ?Sized, N: Notify> AsMut<Self> for SharedRb<S, N> {
     fn as_mut(&mut self) -> &mut Self {
         self
     }
@@ -1,5 +1,12 @@
+use crate::utils::div_rem;
 use core::{num::NonZeroUsize, ops::Range};
 
+/// Maximal capacity of a ring buffer.
+///
+/// Indices are kept below `2 * capacity`, so this limit guarantees that index arithmetic like `2 * capacity + index` never overflows.
+/// It may only be reached by storages of zero-sized items.
+pub const MAX_CAPACITY: usize = usize::MAX / 4;
+
 /// Returns a pair of ranges between `start` and `end` indices in a ring buffer with specific `capacity`.
 ///
 /// `start` and `end` may be arbitrary large, but must satisfy the following condition: `0 <= (start - end) % (2 * capacity) <= capacity`.
@@ -7,8 +14,8 @@ use core::{num::NonZeroUsize, ops::Range};
 ///
 /// The first range starts from `start`. If the first slice is empty then second slice is empty too.
 pub fn ranges(capacity: NonZeroUsize, start: usize, end: usize) -> (Range<usize>, Range<usize>) {
-    let (head_quo, head_rem) = (start / capacity, start % capacity);
-    let (tail_quo, tail_rem) = (end / capacity, end % capacity);
+    let (head_quo, head_rem) = div_rem(start, capacity);
+    let (tail_quo, tail_rem) = div_rem(end, capacity);
 
     if (head_quo + tail_quo) % 2 == 0 {
         (head_rem..tail_rem, 0..0)
@@ -0,0 +1,75 @@
+#[cfg(feature = "alloc")]
+use crate::rb::utils::MAX_CAPACITY;
+use crate::traits::Consumer;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::mem::MaybeUninit;
+#[cfg(feature = "alloc")]
+use serde::{de::Error, Deserialize, Deserializer};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+const NAME: &str = "RingBuffer";
+
+struct Items<'a, C: Consumer + ?Sized>(&'a C);
+
+impl<'a, C: Consumer + ?Sized> Serialize for Items<'a, C>
+where
+    C::Item: Serialize,
+{
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        let (left, right) = self.0.as_slices();
+        serializer.collect_seq(left.iter().chain(right))
+    }
+}
+
+/// Serializes ring buffer capacity and occupied items in order from the eldest to the latest.
+pub fn serialize<C: Consumer + ?Sized, Z: Serializer>(this: &C, serializer: Z) -> Result<Z::Ok, Z::Error>
+where
+    C::Item: Serialize,
+{
+    let mut state = serializer.serialize_struct(NAME, 2)?;
+    state.serialize_field("capacity", &this.capacity())?;
+    state.serialize_field("items", &Items(this))?;
+    state.end()
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Deserialize)]
+#[serde(rename = "RingBuffer")]
+struct Contents<T> {
+    capacity: usize,
+    items: Vec<T>,
+}
+
+/// Deserializes ring buffer capacity and items and places them into a storage of that capacity.
+///
+/// Returns the storage and the number of items at its beginning.
+///
+/// Capacity comes from untrusted input and may be much larger than the input itself,
+/// so the storage is allocated fallibly and an error is returned instead of aborting on allocation failure.
+///
+/// Fails if capacity is zero or greater than `usize::MAX / 4`, there are more items than capacity or the storage cannot be allocated.
+#[cfg(feature = "alloc")]
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<(Box<[MaybeUninit<T>]>, usize), D::Error> {
+    let Contents { capacity, items } = Contents::deserialize(deserializer)?;
+    if capacity == 0 {
+        return Err(D::Error::custom("ring buffer capacity must be non-zero"));
+    }
+    if capacity > MAX_CAPACITY {
+        return Err(D::Error::custom("ring buffer capacity is too large"));
+    }
+    if items.len() > capacity {
+        return Err(D::Error::invalid_length(items.len(), &"number of items not greater than capacity"));
+    }
+
+    let mut storage = Vec::<MaybeUninit<T>>::new();
+    storage
+        .try_reserve_exact(capacity)
+        .map_err(|_| D::Error::custom("failed to allocate ring buffer storage"))?;
+    let count = items.len();
+    storage.extend(items.into_iter().map(MaybeUninit::new));
+    // Remaining slots are uninitialized, that is valid for `MaybeUninit`.
+    unsafe { storage.set_len(capacity) };
+    Ok((storage.into_boxed_slice(), count))
+}
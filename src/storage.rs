@@ -91,6 +91,14 @@ pub struct Owning<T: ?Sized> {
     data: UnsafeCell<T>,
 }
 unsafe impl<T: ?Sized> Sync for Owning<T> where T: Send {}
+impl<T> Owning<T> {
+    /// Wrap owned data into storage.
+    pub const fn new(value: T) -> Self {
+        Self {
+            data: UnsafeCell::new(value),
+        }
+    }
+}
 impl<T> From<T> for Owning<T> {
     fn from(value: T) -> Self {
         Self {
@@ -244,3 +244,142 @@ fn push_pop() {
     }
     assert_eq!(prod.occupied_len(), 0);
 }
+
+#[test]
+fn storage_ptr_cap() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (base, cap) = unsafe { rb.storage_ptr_cap() };
+    assert_eq!(cap, 3);
+
+    assert_eq!(rb.push_iter(0..2), 2);
+    assert_eq!(rb.skip(1), 1);
+    assert_eq!(rb.as_slices().0.as_ptr(), unsafe { base.add(1) }.cast());
+}
+
+#[test]
+fn retain() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[0; 5]);
+    cons.skip(5);
+    prod.push_iter(0..8);
+
+    cons.retain(|x| x % 3 != 0);
+    assert!(cons.iter().copied().eq([1, 2, 4, 5, 7]));
+    assert_eq!(prod.vacant_len(), 3);
+
+    cons.retain(|_| true);
+    assert_eq!(cons.occupied_len(), 5);
+    cons.retain(|_| false);
+    assert!(cons.is_empty());
+
+    prod.push_iter(0..4);
+    assert!(cons.pop_iter().eq(0..4));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn retain_dedup() {
+    use std::collections::HashSet;
+
+    let mut rb = Rb::<Array<(i32, usize), 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[(0, 0); 5]);
+    cons.skip(5);
+    prod.push_iter([1, 2, 1, 3, 2, 1].into_iter().enumerate().map(|(i, x)| (x, i)));
+
+    let mut seen = HashSet::new();
+    cons.retain(|(x, _)| seen.insert(*x));
+    assert!(cons.iter().copied().eq([(1, 0), (2, 1), (3, 3)]));
+    assert_eq!(prod.vacant_len(), 5);
+}
+
+#[test]
+fn first_last() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    assert_eq!(rb.first(), None);
+    assert_eq!(rb.last(), None);
+
+    rb.push_slice(&[0, 1]);
+    assert_eq!((rb.first(), rb.last()), (Some(&0), Some(&1)));
+
+    rb.skip(2);
+    rb.push_slice(&[2, 3]);
+    assert_eq!(rb.as_slices(), (&[2][..], &[3][..]));
+    assert_eq!((rb.first(), rb.last()), (Some(&2), Some(&3)));
+
+    *rb.first_mut().unwrap() = 4;
+    *rb.last_mut().unwrap() = 5;
+    assert!(rb.iter().copied().eq([4, 5]));
+
+    rb.skip(1);
+    assert_eq!((rb.first(), rb.last()), (Some(&5), Some(&5)));
+}
+
+#[test]
+fn advance_checked() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let (left, right) = prod.vacant_slices_mut();
+    for (i, place) in left.iter_mut().chain(right).take(2).enumerate() {
+        place.write(i as i32);
+    }
+    assert_eq!(unsafe { prod.advance_write_index_checked(2) }, Ok(()));
+    assert_eq!(unsafe { prod.advance_write_index_checked(2) }, Err(1));
+    assert_eq!(cons.occupied_len(), 2);
+
+    assert_eq!(unsafe { cons.advance_read_index_checked(3) }, Err(2));
+    assert_eq!(cons.as_slices(), (&[0, 1][..], &[][..]));
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(unsafe { cons.advance_read_index_checked(0) }, Ok(()));
+}
+
+#[test]
+fn ranges() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.occupied_ranges(), (0..0, 0..0));
+    assert_eq!(prod.vacant_ranges(), (0..4, 0..0));
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(cons.occupied_ranges(), (2..3, 0..0));
+    assert_eq!(prod.vacant_ranges(), (3..4, 0..2));
+
+    assert_eq!(prod.push_slice(&[3, 4]), 2);
+    assert_eq!(cons.occupied_ranges(), (2..4, 0..1));
+    assert_eq!(prod.vacant_ranges(), (1..2, 0..0));
+    assert_eq!(cons.observe().occupied_ranges(), cons.occupied_ranges());
+
+    let (left, right) = cons.as_slices();
+    let (first, second) = cons.occupied_ranges();
+    assert_eq!((left.len(), right.len()), (first.len(), second.len()));
+}
+
+#[test]
+fn position() {
+    let mut rb = Rb::<Array<u8, 6>>::default();
+    assert_eq!(rb.position(|_| true), None);
+    assert_eq!(rb.find_byte(b'\n'), None);
+
+    rb.push_slice(b"ab\ncd");
+    rb.skip(3);
+    rb.push_slice(b"e\nf");
+    assert_eq!(rb.as_slices(), (&b"cde"[..], &b"\nf"[..]));
+
+    assert_eq!(rb.position(|b| *b == b'd'), Some(1));
+    assert_eq!(rb.position(|b| *b == b'f'), Some(4));
+    assert_eq!(rb.position(|b| *b == b'a'), None);
+    assert_eq!(rb.find_byte(b'\n'), Some(3));
+    assert_eq!(rb.find_byte(b'c'), Some(0));
+    assert_eq!(rb.find_byte(b'x'), None);
+
+    let mut line = [0; 4];
+    assert_eq!(rb.pop_slice(&mut line), 4);
+    assert_eq!(&line, b"cde\n");
+}
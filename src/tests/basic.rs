@@ -165,3 +165,182 @@ fn len_remaining() {
     assert_eq!(prod.vacant_len(), 1);
     assert_eq!(cons.vacant_len(), 1);
 }
+
+#[test]
+fn occupied_since() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..2), 2);
+    let saved = prod.write_index();
+    assert_eq!(cons.occupied_since(saved), 0);
+    assert!(cons.as_slices_since(saved).0.is_empty());
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.push_iter(2..4), 2);
+    assert_eq!(cons.occupied_since(saved), 2);
+    let (left, right) = cons.as_slices_since(saved);
+    assert_eq!((left, right), (&[2][..], &[3][..]));
+
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(cons.occupied_since(saved), 2);
+    assert!(cons.as_slices_since(saved).0.iter().eq(&[3]));
+
+    assert_eq!(cons.skip(1), 1);
+    assert_eq!(prod.push_iter(4..7), 3);
+    assert_eq!(cons.occupied_since(saved), 3);
+    let (left, right) = cons.as_slices_since(saved);
+    assert!(left.iter().chain(right).eq(&[4, 5, 6]));
+}
+
+#[test]
+fn push_status() {
+    use crate::producer::PushStatus;
+
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_status(0), PushStatus::Accepted { remaining_vacant: 2 });
+    assert_eq!(prod.push_status(1), PushStatus::Accepted { remaining_vacant: 1 });
+    assert_eq!(prod.push_status(2), PushStatus::AcceptedLast);
+    assert_eq!(prod.push_status(3), PushStatus::Rejected(3));
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.push_status(3), PushStatus::AcceptedLast);
+}
+
+#[test]
+fn wrap_mask() {
+    let rb = Rb::<Array<i32, 4>>::default();
+    assert!(rb.capacity_is_pow2());
+    assert_eq!(rb.wrap_mask(), Some(3));
+
+    let rb = Rb::<Array<i32, 3>>::default();
+    assert!(!rb.capacity_is_pow2());
+    assert_eq!(rb.wrap_mask(), None);
+}
+
+#[test]
+fn overrun_since() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+
+    assert_eq!(rb.push_iter(0..2), 2);
+    let cursor = rb.read_index();
+    assert_eq!(rb.overrun_since(cursor), 0);
+    assert_eq!(rb.overrun_since(rb.write_index()), 0);
+
+    rb.push_overwrite(2);
+    assert_eq!(rb.overrun_since(cursor), 0);
+    rb.push_overwrite(3);
+    assert_eq!(rb.overrun_since(cursor), 1);
+    rb.push_overwrite(4);
+    assert_eq!(rb.overrun_since(cursor), 2);
+    assert_eq!(rb.occupied_since(cursor), 3);
+}
+
+#[test]
+fn lag() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+
+    let cursor = rb.write_index();
+    assert_eq!(rb.push_iter(0..2), 2);
+    assert_eq!(rb.lag(cursor), 2);
+    assert!(!rb.is_lagging(cursor));
+
+    rb.push_overwrite(2);
+    assert_eq!(rb.lag(cursor), 3);
+    assert!(!rb.is_lagging(cursor));
+
+    rb.push_overwrite(3);
+    assert_eq!(rb.lag(cursor), 3);
+    assert!(rb.is_lagging(cursor));
+    assert_eq!(rb.overrun_since(cursor), 1);
+
+    let cursor = rb.read_index();
+    assert_eq!(rb.lag(cursor), 3);
+    assert!(!rb.is_lagging(cursor));
+}
+
+#[test]
+fn rotate() {
+    let mut rb = Rb::<Array<i32, 5>>::default();
+    rb.rotate_left(1);
+    assert!(rb.is_empty());
+
+    assert_eq!(rb.push_iter(0..4), 4);
+    rb.rotate_left(1);
+    assert!(rb.iter().copied().eq([1, 2, 3, 0]));
+    rb.rotate_right(2);
+    assert!(rb.iter().copied().eq([3, 0, 1, 2]));
+    rb.rotate_left(9);
+    assert!(rb.iter().copied().eq([0, 1, 2, 3]));
+    rb.rotate_right(4);
+    assert!(rb.iter().copied().eq([0, 1, 2, 3]));
+}
+
+#[cfg(feature = "overflow-flag")]
+#[test]
+fn overflow_flag() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    assert!(!rb.has_been_full());
+    {
+        let (mut prod, mut cons) = rb.split_ref();
+        prod.try_push(0).unwrap();
+        assert!(!cons.has_been_full());
+        prod.try_push(1).unwrap();
+        assert!(cons.has_been_full());
+        cons.try_pop().unwrap();
+        assert!(prod.has_been_full());
+        prod.clear_overflow_flag();
+        assert!(!cons.has_been_full());
+    }
+    assert!(!rb.has_been_full());
+
+    assert!(Rb::from([0, 1]).has_been_full());
+}
+
+#[cfg(feature = "high-water-mark")]
+#[test]
+fn high_water_mark() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.high_water_mark(), 0);
+
+    prod.push_slice(&[0, 1, 2]);
+    cons.skip(3);
+    prod.try_push(3).unwrap();
+    assert_eq!(cons.high_water_mark(), 3);
+
+    cons.reset_high_water_mark();
+    assert_eq!(prod.high_water_mark(), 1);
+    prod.try_push(4).unwrap();
+    assert_eq!(cons.high_water_mark(), 2);
+
+    assert_eq!(Rb::from([0, 1]).high_water_mark(), 2);
+}
+
+#[test]
+fn index_padding() {
+    let size = core::mem::size_of::<crate::SharedRb<Array<u8, 1>>>();
+    assert_eq!(size >= 64, !cfg!(feature = "no-cache-padding"));
+}
+
+#[test]
+fn positions() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let obs = prod.observe();
+
+    let start = obs.positions();
+    assert_eq!(start, (0, 0));
+
+    prod.try_push(0).unwrap();
+    let pushed = obs.positions();
+    assert_eq!(pushed.0, start.0);
+    assert_ne!(pushed.1, start.1);
+
+    cons.try_pop().unwrap();
+    let popped = obs.positions();
+    assert_ne!(popped.0, pushed.0);
+    assert_eq!(popped.1, pushed.1);
+}
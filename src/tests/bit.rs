@@ -0,0 +1,51 @@
+use crate::{storage::Array, BitRb};
+
+#[test]
+fn push_pop() {
+    let mut rb = BitRb::<Array<usize, 1>>::default();
+    let cap = rb.capacity().get();
+    assert_eq!(cap, usize::BITS as usize);
+
+    assert_eq!(rb.pop_bit(), None);
+    for i in 0..cap {
+        assert_eq!(rb.push_bit(i % 3 == 0), Ok(()));
+    }
+    assert!(rb.is_full());
+    assert_eq!(rb.push_bit(true), Err(true));
+
+    for i in 0..(cap / 2) {
+        assert_eq!(rb.pop_bit(), Some(i % 3 == 0));
+    }
+    // Wrap around the end of storage.
+    for i in 0..(cap / 2) {
+        assert_eq!(rb.push_bit(i % 2 == 0), Ok(()));
+    }
+    for i in (cap / 2)..cap {
+        assert_eq!(rb.pop_bit(), Some(i % 3 == 0));
+    }
+    for i in 0..(cap / 2) {
+        assert_eq!(rb.pop_bit(), Some(i % 2 == 0));
+    }
+    assert!(rb.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn slices() {
+    let mut rb = BitRb::new(10);
+    assert_eq!(rb.capacity().get(), 10);
+
+    assert_eq!(rb.push_bits(&[true, false, true, true]), 4);
+    let mut bits = [false; 3];
+    assert_eq!(rb.pop_bits(&mut bits), 3);
+    assert_eq!(bits, [true, false, true]);
+
+    assert_eq!(rb.push_bits(&[false; 7]), 7);
+    assert_eq!(rb.push_bits(&[true; 4]), 2);
+    assert_eq!(rb.occupied_len(), 10);
+
+    let mut bits = [false; 12];
+    assert_eq!(rb.pop_bits(&mut bits), 10);
+    assert_eq!(bits[..10], [true, false, false, false, false, false, false, false, true, true]);
+    assert_eq!(rb.clear(), 0);
+}
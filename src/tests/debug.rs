@@ -0,0 +1,47 @@
+use super::Rb;
+use crate::{storage::Array, traits::*, Cons, Obs, Prod};
+use alloc::format;
+
+struct NotDebug;
+
+#[test]
+fn state() {
+    let mut rb = Rb::<Array<NotDebug, 4>>::default();
+    rb.try_push(NotDebug).ok();
+    let state = "{ capacity: 4, occupied_len: 1, vacant_len: 3 }";
+    assert!(format!("{:?}", rb).ends_with(&format!("Rb {}", state)));
+
+    let (prod, cons) = rb.split_ref();
+    assert!(format!("{:?}", prod).ends_with(&format!("Prod {}", state)));
+    assert!(format!("{:?}", cons).ends_with(&format!("Cons {}", state)));
+}
+
+#[test]
+fn direct() {
+    let rb = Rb::<Array<NotDebug, 2>>::default();
+    let (prod, cons, obs) = (Prod::new(&rb), Cons::new(&rb), Obs::new(&rb));
+    assert_eq!(format!("{:?}", prod), "Prod { capacity: 2, occupied_len: 0, vacant_len: 2 }");
+    assert_eq!(format!("{:?}", cons), "Cons { capacity: 2, occupied_len: 0, vacant_len: 2 }");
+    assert_eq!(format!("{:?}", obs), "Obs { capacity: 2, occupied_len: 0, vacant_len: 2 }");
+}
+
+#[test]
+fn status() {
+    use crate::traits::observer::BufferStatus;
+
+    let mut rb = Rb::<Array<i32, 16>>::default();
+    rb.push_iter(0..7);
+    let (prod, cons) = rb.split_ref();
+
+    let status = prod.status();
+    assert_eq!(
+        status,
+        BufferStatus {
+            occupied: 7,
+            vacant: 9,
+            capacity: 16
+        }
+    );
+    assert_eq!(cons.status(), status);
+    assert_eq!(format!("{}", status), "7/16");
+}
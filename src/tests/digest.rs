@@ -0,0 +1,47 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+use core::hash::Hasher;
+use std::vec::Vec;
+
+#[derive(Default)]
+struct Collect(Vec<u8>);
+
+impl Hasher for Collect {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+    fn finish(&self) -> u64 {
+        self.0.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b))
+    }
+}
+
+#[test]
+fn digest_wrapped() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+    assert!(!cons.as_slices().1.is_empty());
+
+    let mut hasher = Collect::default();
+    cons.digest(&mut hasher);
+    assert_eq!(hasher.0, [2, 3, 4, 5]);
+    assert_eq!(hasher.finish(), 0x02030405);
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[cfg(feature = "crc")]
+#[test]
+fn checksum_crc32() {
+    let mut rb = Rb::<Array<u8, 9>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(b"xxxx"), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.push_slice(b"123456789"), 9);
+
+    assert_eq!(cons.checksum_crc32(), 0xcbf43926);
+    assert_eq!(cons.occupied_len(), 9);
+}
@@ -1,7 +1,11 @@
 use super::Rb;
-use crate::{storage::Array, traits::*};
-use alloc::collections::BTreeSet;
-use core::cell::RefCell;
+use crate::{
+    storage::{Array, Heap, Ref},
+    traits::*,
+    utils::uninit_array,
+};
+use alloc::{boxed::Box, collections::BTreeSet};
+use core::{cell::RefCell, mem::MaybeUninit};
 
 #[derive(Debug)]
 struct Dropper<'a> {
@@ -106,3 +110,225 @@ fn transaction() {
     drop(rb);
     assert_eq!(set.borrow().len(), 0);
 }
+
+#[test]
+fn consume_front_while() {
+    use core::ops::ControlFlow;
+
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    for id in 0..4 {
+        rb.try_push(Dropper::new(&set, id)).unwrap();
+    }
+    assert_eq!(set.borrow().len(), 4);
+
+    let mut visited = 0;
+    let count = rb.consume_front_while(|item| {
+        visited += 1;
+        if item.id < 2 {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    assert_eq!(count, 2);
+    assert_eq!(visited, 3);
+    assert_eq!(set.borrow().len(), 2);
+    assert_eq!(rb.first().unwrap().id, 2);
+
+    assert_eq!(rb.consume_front_while(|_| ControlFlow::Continue(())), 2);
+    assert!(rb.is_empty());
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn pop_ref() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 2>>::default();
+    rb.try_push(Dropper::new(&set, 1)).unwrap();
+    rb.try_push(Dropper::new(&set, 2)).unwrap();
+
+    {
+        let mut item = rb.try_pop_ref().unwrap();
+        assert_eq!(item.id, 1);
+        item.id = 3;
+        set.borrow_mut().insert(3);
+        set.borrow_mut().remove(&1);
+    }
+    assert_eq!(set.borrow().len(), 1);
+    assert_eq!(rb.occupied_len(), 1);
+
+    assert_eq!(rb.try_pop_ref().unwrap().id, 2);
+    assert_eq!(set.borrow().len(), 0);
+    assert!(rb.try_pop_ref().is_none());
+}
+
+#[test]
+fn overwrite() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 2>>::default();
+    assert!(rb.push_overwrite(Dropper::new(&set, 1)).is_none());
+    assert!(rb.push_overwrite(Dropper::new(&set, 2)).is_none());
+
+    let evicted = rb.push_overwrite(Dropper::new(&set, 3)).unwrap();
+    assert_eq!(evicted.id, 1);
+    assert_eq!(set.borrow().len(), 3);
+    drop(evicted);
+    assert_eq!(set.borrow().len(), 2);
+
+    rb.push_iter_overwrite([4, 5, 6].into_iter().map(|id| Dropper::new(&set, id)));
+    assert!(set.borrow().iter().copied().eq([5, 6]));
+
+    drop(rb);
+    assert!(set.borrow().is_empty());
+}
+
+#[test]
+fn resize() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<crate::storage::Heap<Dropper>>::new(2);
+    rb.try_push(Dropper::new(&set, 1)).unwrap();
+    rb.try_push(Dropper::new(&set, 2)).unwrap();
+    rb.try_pop().unwrap();
+    rb.try_push(Dropper::new(&set, 3)).unwrap();
+    assert_eq!(set.borrow().len(), 2);
+
+    rb.resize(core::num::NonZeroUsize::new(4).unwrap()).unwrap();
+    assert_eq!(set.borrow().len(), 2);
+    assert_eq!(rb.first().unwrap().id, 2);
+    assert_eq!(rb.last().unwrap().id, 3);
+
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn push_array() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 3>>::default();
+    rb.push_array([Dropper::new(&set, 1), Dropper::new(&set, 2)]).unwrap();
+    assert_eq!(set.borrow().len(), 2);
+
+    let rejected = rb.push_array([Dropper::new(&set, 3), Dropper::new(&set, 4)]).unwrap_err();
+    assert_eq!(rb.occupied_len(), 2);
+    assert_eq!(rejected[0].id, 3);
+    drop(rejected);
+    assert_eq!(set.borrow().len(), 2);
+
+    rb.clear();
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn pop_array() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 3>>::default();
+    rb.push_array([Dropper::new(&set, 1), Dropper::new(&set, 2), Dropper::new(&set, 3)])
+        .unwrap();
+
+    let [a, b] = rb.pop_array().unwrap();
+    assert_eq!((a.id, b.id), (1, 2));
+    assert_eq!(set.borrow().len(), 3);
+    drop(a);
+    assert_eq!(set.borrow().len(), 2);
+    assert!(rb.pop_array::<2>().is_none());
+
+    drop(b);
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn uninit_slice() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut mem: [MaybeUninit<Dropper>; 3] = uninit_array();
+    {
+        let mut rb = Rb::<Ref<Dropper>>::from(&mut mem[..]);
+        rb.try_push(Dropper::new(&set, 1)).unwrap();
+        rb.try_push(Dropper::new(&set, 2)).unwrap();
+        rb.try_pop().unwrap();
+        assert_eq!(set.borrow().len(), 1);
+    }
+    assert_eq!(set.borrow().len(), 0);
+    assert_eq!(mem.len(), 3);
+}
+
+#[test]
+fn uninit_boxed_slice() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let boxed = Box::new(uninit_array::<Dropper, 3>()) as Box<[MaybeUninit<Dropper>]>;
+    let mut rb = Rb::<Heap<Dropper>>::from_boxed_slice(boxed);
+    rb.try_push(Dropper::new(&set, 1)).unwrap();
+    rb.try_push(Dropper::new(&set, 2)).unwrap();
+    assert_eq!(set.borrow().len(), 2);
+
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn retain() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.push_iter((0..3).map(|i| Dropper::new(&set, i)));
+    rb.skip(3);
+    rb.push_iter((0..4).map(|i| Dropper::new(&set, i)));
+
+    rb.retain(|d| d.id % 2 == 1);
+    assert!(rb.iter().map(|d| d.id).eq([1, 3]));
+    assert_eq!(set.borrow().len(), 2);
+
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn retain_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 6>>::default();
+    rb.push_iter((0..6).map(|i| Dropper::new(&set, i)));
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        rb.retain(|d| match d.id {
+            3 => panic!(),
+            id => id != 1,
+        })
+    }));
+    assert!(res.is_err());
+    assert!(rb.iter().map(|d| d.id).eq([0, 2, 3, 4, 5]));
+    assert_eq!(set.borrow().len(), 5);
+
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn drain() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Array<Dropper, 4>>::default();
+    rb.push_iter((0..4).map(|i| Dropper::new(&set, i)));
+
+    let mut iter = rb.drain();
+    let item = iter.next().unwrap();
+    assert_eq!(item.id, 0);
+    drop(iter);
+    assert_eq!(set.borrow().len(), 1);
+    assert!(rb.is_empty());
+
+    drop(item);
+    assert_eq!(set.borrow().len(), 0);
+}
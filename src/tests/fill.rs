@@ -0,0 +1,42 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+
+#[test]
+fn fill_with() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    rb.try_push(1).unwrap();
+    rb.try_push(2).unwrap();
+    assert_eq!(rb.try_pop(), Some(1));
+
+    rb.fill_with(7);
+    assert!(rb.is_full());
+    assert!(rb.iter().copied().eq([7; 3]));
+}
+
+#[test]
+fn fill_bytes() {
+    let mut rb = Rb::<Array<u8, 5>>::default();
+    assert_eq!(rb.push_slice(&[1, 2, 3, 4]), 4);
+    assert_eq!(rb.skip(3), 3);
+
+    rb.fill_bytes(0);
+    assert!(rb.is_full());
+    assert!(!rb.as_slices().1.is_empty());
+    assert!(rb.iter().copied().eq([0; 5]));
+}
+
+#[test]
+fn replace_all() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    rb.push_slice(&[0, 1]);
+    rb.skip(1);
+
+    assert_eq!(rb.replace_all([2, 3]), 2);
+    assert!(rb.iter().copied().eq([2, 3]));
+
+    assert_eq!(rb.replace_all(4..8), 3);
+    assert!(rb.iter().copied().eq(4..7));
+
+    assert_eq!(rb.replace_all([]), 0);
+    assert!(rb.is_empty());
+}
@@ -1,5 +1,5 @@
 use super::Rb;
-use crate::{storage::Array, traits::*};
+use crate::{storage::Array, traits::*, CachingCons, CachingProd};
 
 #[test]
 fn producer() {
@@ -113,3 +113,46 @@ fn consumer() {
     assert_eq!(frozen_cons.occupied_len(), 1);
     assert_eq!(prod.occupied_len(), 1);
 }
+
+#[test]
+fn caching_auto_sync() {
+    let rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = (CachingProd::new(&rb), CachingCons::new(&rb));
+    let obs = prod.observe();
+
+    prod.set_auto_sync(false);
+    prod.try_push(0).unwrap();
+    prod.push_slice(&[1, 2]);
+    assert_eq!(obs.occupied_len(), 0);
+    assert_eq!(cons.occupied_len(), 0);
+
+    prod.sync();
+    assert_eq!(obs.occupied_len(), 3);
+
+    cons.set_auto_sync(false);
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(obs.occupied_len(), 3);
+    cons.set_auto_sync(true);
+    assert_eq!(obs.occupied_len(), 2);
+
+    prod.push_slice(&[3, 4]);
+    assert_eq!(obs.occupied_len(), 2);
+    drop(prod);
+    assert_eq!(obs.occupied_len(), 4);
+}
+
+#[cfg(all(feature = "alloc", not(feature = "test_local")))]
+#[test]
+fn split_caching() {
+    use alloc::sync::Arc;
+
+    let rb = Arc::new(Rb::from([0, 1, 2]));
+    let (mut prod, mut cons): (CachingProd<_>, CachingCons<_>) = rb.clone().split();
+    assert!(prod.is_full());
+    assert_eq!(cons.occupied_len(), 3);
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(rb.occupied_len(), 2);
+    prod.try_push(3).unwrap();
+    assert!(cons.pop_iter().eq(1..4));
+}
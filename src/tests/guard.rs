@@ -0,0 +1,104 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+
+#[test]
+fn write() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.try_push(0).unwrap();
+    cons.try_pop().unwrap();
+
+    let mut guard = prod.write_guard();
+    assert_eq!(guard.len(), 4);
+    {
+        let (left, right) = guard.vacant_slices_mut();
+        assert_eq!(left.len(), 3);
+        assert_eq!(right.len(), 1);
+        for (i, place) in left.iter_mut().chain(right.iter_mut()).enumerate() {
+            place.write(i as i32 + 1);
+        }
+    }
+    unsafe { guard.commit(4) };
+    assert!(prod.is_full());
+    assert!(cons.pop_iter().eq(1..5));
+
+    prod.write_guard().vacant_slices_mut().0[0].write(5);
+    assert!(prod.is_empty());
+
+    let mut guard = prod.write_guard();
+    guard.vacant_slices_mut().0[0].write(6);
+    unsafe { guard.commit(1) };
+    assert_eq!(cons.try_pop(), Some(6));
+}
+
+#[test]
+#[should_panic]
+fn write_commit_too_many() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (mut prod, _) = rb.split_ref();
+    prod.try_push(0).unwrap();
+
+    let guard = prod.write_guard();
+    unsafe { guard.commit(2) };
+}
+
+#[test]
+fn read() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(b"ab");
+    cons.skip(2);
+    prod.push_slice(b"cd\ne");
+
+    let pos = {
+        let guard = cons.read_guard();
+        assert_eq!(guard.as_slices(), (b"cd".as_ref(), b"\ne".as_ref()));
+        guard.as_slices().1.iter().position(|&b| b == b'\n').unwrap()
+    };
+    assert_eq!(cons.occupied_len(), 4);
+
+    let mut guard = cons.read_guard();
+    guard.as_mut_slices().0[0] = b'C';
+    guard.commit(2 + pos + 1);
+    assert_eq!(prod.vacant_len(), 3);
+    assert_eq!(cons.try_pop(), Some(b'e'));
+}
+
+#[test]
+#[should_panic]
+fn read_commit_too_many() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    prod.try_push(0).unwrap();
+
+    cons.read_guard().commit(2);
+}
+
+#[test]
+fn reserve() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert!(prod.reserve(4).is_none());
+
+    let mut reservation = prod.reserve(2).unwrap();
+    assert_eq!(reservation.len(), 2);
+    {
+        let (left, right) = reservation.vacant_slices_mut();
+        assert_eq!((left.len(), right.len()), (1, 1));
+        for (i, place) in left.iter_mut().chain(right.iter_mut()).enumerate() {
+            place.write(i as i32 + 3);
+        }
+    }
+    unsafe { reservation.commit() };
+    assert_eq!(cons.occupied_len(), 3);
+
+    prod.reserve(1).unwrap().vacant_slices_mut().0[0].write(5);
+    assert_eq!(cons.occupied_len(), 3);
+    assert!(prod.reserve(0).unwrap().is_empty());
+    assert!(cons.pop_iter().eq(2..5));
+}
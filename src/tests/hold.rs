@@ -16,6 +16,27 @@ fn split_and_drop() {
     assert!(!obs.write_is_held() && !obs.read_is_held());
 }
 
+#[test]
+fn abandoned() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (prod, cons) = rb.split_ref();
+    let obs = prod.observe();
+    let obs_clone = obs.clone();
+    assert!(!prod.is_abandoned() && !cons.is_abandoned() && !obs.is_abandoned());
+
+    drop(cons);
+    assert!(prod.is_abandoned());
+    assert!(!obs.is_abandoned());
+
+    drop(prod);
+    assert!(obs.is_abandoned() && obs_clone.is_abandoned());
+
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (prod, cons) = rb.split_ref();
+    drop(prod);
+    assert!(cons.is_abandoned());
+}
+
 #[test]
 fn manually_hold_and_drop() {
     let rb = Rb::<Array<i32, 2>>::default();
@@ -42,3 +63,20 @@ fn hold_conflict() {
     let _prod = CachingProd::new(&rb);
     CachingProd::new(&rb);
 }
+
+#[test]
+fn clone_obs() {
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (prod, cons) = rb.split_ref();
+    let obs = prod.observe();
+
+    let clones = [(); 4].map(|()| obs.clone());
+    assert!(clones.iter().all(|obs| obs.write_is_held() && obs.read_is_held()));
+    drop(clones);
+    assert!(obs.write_is_held() && obs.read_is_held());
+
+    drop((prod, cons));
+    let clone = obs.clone();
+    drop(obs);
+    assert!(!clone.write_is_held() && !clone.read_is_held());
+}
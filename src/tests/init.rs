@@ -1,9 +1,10 @@
 use super::Rb;
 #[cfg(feature = "alloc")]
 use crate::storage::Heap;
-use crate::traits::*;
+use crate::{storage::Ref, traits::*};
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, vec::Vec};
+use core::mem::MaybeUninit;
 
 #[test]
 fn from_array() {
@@ -20,6 +21,24 @@ fn from_array() {
     assert_eq!(cons.try_pop(), None);
 }
 
+#[test]
+fn from_uninit_slice() {
+    let mut mem = [MaybeUninit::<i32>::uninit(); 3];
+    let mut rb = Rb::<Ref<i32>>::from(&mut mem[..2]);
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.capacity().get(), 2);
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.try_push(123), Ok(()));
+    assert_eq!(prod.try_push(321), Ok(()));
+    assert_eq!(prod.try_push(444), Err(444));
+
+    assert_eq!(cons.try_pop(), Some(123));
+    assert_eq!(cons.try_pop(), Some(321));
+    assert_eq!(cons.try_pop(), None);
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn new() {
@@ -73,3 +92,60 @@ fn from_boxed_slice() {
     assert_eq!(cons.try_pop(), Some(321));
     assert_eq!(cons.try_pop(), None);
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn from_uninit_boxed_slice() {
+    let boxed = Box::new([MaybeUninit::<i32>::uninit(); 3]) as Box<[MaybeUninit<i32>]>;
+    let rb = Rb::<Heap<i32>>::from_boxed_slice(boxed);
+    let (mut prod, mut cons) = rb.split();
+
+    assert_eq!(prod.capacity().get(), 3);
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.push_slice(&[0, 1, 2, 3]), 3);
+    assert!(cons.pop_iter().eq(0..3));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn with_capacity_filled() {
+    let rb = Rb::<Heap<i32>>::with_capacity_filled(4, 0..2);
+    assert_eq!(rb.capacity().get(), 4);
+    assert!(rb.iter().copied().eq(0..2));
+
+    let mut iter = 0..6;
+    let rb = Rb::<Heap<i32>>::with_capacity_filled(4, &mut iter);
+    assert!(rb.is_full());
+    assert!(rb.iter().copied().eq(0..4));
+    assert!(iter.eq(4..6));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn from_iter() {
+    let rb: Rb<Heap<i32>> = (0..8).collect();
+    assert_eq!(rb.capacity().get(), 8);
+    assert_eq!(rb.read_index(), 0);
+    assert!(rb.is_full());
+    assert!(rb.iter().copied().eq(0..8));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn with_capacity_pow2() {
+    let rb = Rb::<Heap<i32>>::with_capacity_pow2(5);
+    assert_eq!(rb.capacity().get(), 8);
+    assert!(rb.capacity_is_pow2());
+
+    let rb = Rb::<Heap<i32>>::with_capacity_pow2(4);
+    assert_eq!(rb.capacity().get(), 4);
+
+    let (mut prod, mut cons) = rb.split();
+    for i in 0..6 {
+        assert_eq!(prod.push_iter(0..3), 3);
+        assert_eq!(cons.occupied_len(), 3);
+        assert_eq!(prod.vacant_len(), 1);
+        assert!(cons.pop_iter().eq(0..3), "{}", i);
+    }
+}
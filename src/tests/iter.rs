@@ -77,3 +77,83 @@ fn push_pop_iter_partial() {
     assert_eq!(cons.try_pop().unwrap(), 5);
     assert!(prod.is_empty());
 }
+
+#[test]
+fn push_validated() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut iter = [0, 1, -2, 3].into_iter();
+    assert_eq!(prod.push_iter_validated(&mut iter, |x| *x >= 0), Err((2, -2)));
+    assert_eq!(prod.push_iter_validated(&mut iter, |x| *x >= 0), Ok(1));
+    assert!(iter.next().is_none());
+
+    let mut iter = [4, 5].into_iter();
+    assert_eq!(prod.push_iter_validated(&mut iter, |x| *x >= 0), Ok(1));
+    assert!(iter.eq([5]));
+
+    assert!(cons.pop_iter().eq([0, 1, 3, 4]));
+}
+
+#[test]
+fn pop_iter_bounded() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[0, 1]);
+    {
+        let mut iter = cons.pop_iter();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        prod.try_push(2).unwrap();
+        assert_eq!(iter.next(), Some(0));
+        iter.commit();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+    }
+    assert_eq!(prod.occupied_len(), 1);
+
+    prod.push_slice(&[3, 4]);
+    {
+        let mut iter = cons.pop_iter();
+        assert_eq!(iter.next(), Some(2));
+    }
+    assert!(cons.pop_iter().eq([3, 4]));
+}
+
+#[test]
+fn extend() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.extend(0..2);
+    prod.extend([2, 3, 4, 5].iter().copied());
+    assert!(prod.is_full());
+    assert!(cons.pop_iter().eq(0..4));
+
+    let mut count = 0;
+    prod.extend((0..).inspect(|_| count += 1));
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn drain() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_iter(0..2);
+    cons.skip(2);
+    prod.push_iter(0..4);
+
+    let mut iter = cons.drain();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 3);
+    drop(iter);
+    assert!(cons.is_empty());
+    assert_eq!(prod.vacant_len(), 4);
+
+    prod.push_iter(4..7);
+    assert!(cons.drain().eq(4..7));
+    assert!(cons.is_empty());
+}
@@ -5,17 +5,27 @@ use crate::SharedRb as Rb;
 
 mod access;
 mod basic;
+mod bit;
+#[cfg(feature = "alloc")]
+mod debug;
+#[cfg(feature = "std")]
+mod digest;
 #[cfg(feature = "alloc")]
 mod drop;
+mod fill;
 mod fmt_write;
 mod frozen;
+mod guard;
 mod hold;
 mod init;
 mod iter;
 mod new;
 mod overwrite;
+mod page;
 #[cfg(feature = "std")]
 mod read_write;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+mod serialize;
 #[cfg(feature = "std")]
 mod shared;
 #[cfg(feature = "alloc")]
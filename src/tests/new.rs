@@ -1,7 +1,11 @@
 use super::Rb;
-use crate::{storage::Array, traits::*};
+#[cfg(feature = "alloc")]
+use crate::storage::Heap;
+use crate::{storage::Array, traits::*, CachingCons, CachingProd, StaticRb};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::num::NonZeroUsize;
 
 #[test]
 fn new_static() {
@@ -21,6 +25,21 @@ fn new_static() {
     assert_eq!(cons.try_pop(), None);
 }
 
+#[test]
+fn new_const() {
+    static RB: StaticRb<i32, 2> = StaticRb::new();
+    let mut prod = CachingProd::new(&RB);
+    let mut cons = CachingCons::new(&RB);
+
+    assert_eq!(cons.capacity().get(), 2);
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.push_slice(&[1, 2, 3]), 2);
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(cons.try_pop(), Some(2));
+    assert_eq!(cons.try_pop(), None);
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn from_vec() {
@@ -43,3 +62,27 @@ fn from_vec() {
     assert_eq!(cons.try_pop(), Some(5));
     assert_eq!(cons.try_pop(), None);
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn resize() {
+    let mut rb = Rb::<Heap<i32>>::new(4);
+    rb.push_iter(0..4);
+    rb.skip(2);
+    rb.push_iter(4..6);
+    assert_eq!(rb.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+    assert_eq!(rb.resize(NonZeroUsize::new(3).unwrap()), Err(4));
+    assert!(rb.iter().copied().eq(2..6));
+
+    assert_eq!(rb.resize(NonZeroUsize::new(6).unwrap()), Ok(()));
+    assert_eq!(rb.capacity().get(), 6);
+    assert_eq!(rb.as_slices(), (&[2, 3, 4, 5][..], &[][..]));
+    assert_eq!(rb.push_iter(6..10), 2);
+
+    rb.skip(4);
+    assert_eq!(rb.resize(NonZeroUsize::new(2).unwrap()), Ok(()));
+    assert_eq!(rb.capacity().get(), 2);
+    assert!(rb.is_full());
+    assert!(rb.pop_iter().eq(6..8));
+}
@@ -0,0 +1,37 @@
+use super::Rb;
+use crate::{storage::Array, traits::*, wrap::PageCons};
+
+#[test]
+fn pages() {
+    let mut rb = Rb::<Array<u8, 10>>::default();
+    let (mut prod, cons) = rb.split_ref();
+    let mut cons = PageCons::<_, 4>::new(cons);
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert!(cons.next_page().is_none());
+
+    assert_eq!(prod.push_slice(&[3, 4, 5, 6, 7, 8]), 6);
+    assert_eq!(cons.next_page().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(prod.vacant_len(), 1);
+    assert_eq!(cons.next_page().unwrap(), [4, 5, 6, 7]);
+    assert_eq!(prod.vacant_len(), 5);
+
+    // Page wraps around the end of storage.
+    assert_eq!(prod.push_slice(&[9, 10, 11, 12]), 4);
+    cons.release();
+    assert_eq!(prod.vacant_len(), 5);
+    assert_eq!(cons.next_page().unwrap(), [8, 9, 10, 11]);
+    assert_eq!(prod.vacant_len(), 9);
+    assert!(cons.next_page().is_none());
+
+    let cons = cons.into_inner();
+    assert!(cons.iter().copied().eq([12]));
+}
+
+#[test]
+#[should_panic]
+fn zero_page() {
+    let mut rb = Rb::<Array<u8, 10>>::default();
+    let (_, cons) = rb.split_ref();
+    PageCons::<_, 0>::new(cons);
+}
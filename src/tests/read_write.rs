@@ -1,6 +1,9 @@
 use super::Rb;
 use crate::{storage::Array, traits::*};
-use std::io::{self, Read};
+use std::{
+    io::{self, Read},
+    vec::Vec,
+};
 
 macro_rules! assert_eq_kind {
     ($left:expr, $right:expr) => {
@@ -132,3 +135,57 @@ fn read_from() {
 
     assert!(cons.pop_iter().eq([2; 3]));
 }
+
+#[test]
+fn flush_frozen() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (prod, cons) = rb.split_ref();
+    let mut prod = prod.freeze();
+
+    assert_eq!(io::Write::write(&mut prod, &[0, 1, 2]).unwrap(), 3);
+    assert_eq!(cons.occupied_len(), 0);
+
+    io::Write::flush(&mut prod).unwrap();
+    assert_eq!(cons.occupied_len(), 3);
+    assert!(cons.iter().copied().eq(0..3));
+}
+
+#[test]
+fn read_eof() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let mut buf = [0; 4];
+
+    assert_eq!(
+        cons.eof_reader().read(&mut buf).map_err(|e| e.kind()),
+        Err(io::ErrorKind::WouldBlock)
+    );
+    assert_eq!(cons.eof_reader().read(&mut []).map_err(|e| e.kind()), Ok(0));
+
+    assert_eq!(prod.push_slice(&[1, 2]), 2);
+    drop(prod);
+    assert_eq!(cons.eof_reader().read(&mut buf).map_err(|e| e.kind()), Ok(2));
+    assert_eq!(cons.eof_reader().read(&mut buf).map_err(|e| e.kind()), Ok(0));
+    // Consumer itself keeps reporting an empty buffer.
+    assert_eq!(cons.read(&mut buf).map_err(|e| e.kind()), Err(io::ErrorKind::WouldBlock));
+}
+
+#[test]
+fn vectored() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(3), 3);
+
+    let mut src: &[u8] = &[3, 4, 5, 6, 7];
+    assert_eq!(prod.read_vectored_from(&mut src).unwrap(), 4);
+    assert_eq!(src, [7]);
+    assert_eq!(cons.as_slices(), (&[3][..], &[4, 5, 6][..]));
+    assert_eq!(prod.read_vectored_from(&mut src).unwrap(), 0);
+
+    let mut dst = Vec::new();
+    assert_eq!(cons.write_vectored_to(&mut dst).unwrap(), 4);
+    assert_eq!(dst, [3, 4, 5, 6]);
+    assert!(cons.is_empty());
+    assert_eq!(cons.write_vectored_to(&mut dst).unwrap(), 0);
+}
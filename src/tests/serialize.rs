@@ -0,0 +1,48 @@
+use super::Rb;
+use crate::{storage::Heap, traits::*};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+#[test]
+fn round_trip() {
+    let mut rb = Rb::<Heap<String>>::new(4);
+    rb.push_iter(["a", "b", "c", "d"].into_iter().map(|s| s.to_string()));
+    rb.skip(2);
+    rb.try_push("e".to_string()).unwrap();
+
+    let json = serde_json::to_string(&rb).unwrap();
+    assert_eq!(json, r#"{"capacity":4,"items":["c","d","e"]}"#);
+
+    let rb = serde_json::from_str::<Rb<Heap<String>>>(&json).unwrap();
+    assert_eq!(rb.capacity().get(), 4);
+    assert!(rb.iter().eq(["c", "d", "e"]));
+}
+
+#[test]
+fn consumer() {
+    let mut rb = Rb::<Heap<i32>>::new(3);
+    let (mut prod, cons) = rb.split_ref();
+    prod.push_slice(&[1, 2]);
+    assert_eq!(serde_json::to_string(&cons).unwrap(), r#"{"capacity":3,"items":[1,2]}"#);
+}
+
+#[test]
+fn invalid() {
+    assert!(serde_json::from_str::<Rb<Heap<i32>>>(r#"{"capacity":0,"items":[]}"#).is_err());
+    assert!(serde_json::from_str::<Rb<Heap<i32>>>(r#"{"capacity":1,"items":[1,2]}"#).is_err());
+}
+
+#[test]
+fn huge_capacity() {
+    let json = format!(r#"{{"capacity":{},"items":[1]}}"#, usize::MAX / 4 + 1);
+    assert!(serde_json::from_str::<Rb<Heap<i32>>>(&json).is_err());
+    let json = format!(r#"{{"capacity":{},"items":[1]}}"#, usize::MAX / 4);
+    assert!(serde_json::from_str::<Rb<Heap<i32>>>(&json).is_err());
+
+    let json = format!(r#"{{"capacity":{},"items":[null,null]}}"#, usize::MAX / 4);
+    let rb = serde_json::from_str::<Rb<Heap<()>>>(&json).unwrap();
+    assert_eq!(rb.capacity().get(), usize::MAX / 4);
+    assert_eq!(rb.occupied_len(), 2);
+}
@@ -1,5 +1,16 @@
-use crate::{storage::Heap, traits::*, SharedRb};
-use std::{cell::Cell, thread, thread::sleep, time::Duration, vec::Vec};
+use crate::{rb::Notify, storage::Heap, traits::*, LocalRb, Obs, SharedRb};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    thread::sleep,
+    time::Duration,
+    vec::Vec,
+};
 
 fn yield_() {
     sleep(Duration::from_millis(1));
@@ -68,3 +79,91 @@ fn non_sync() {
     pjh.join().unwrap();
     cjh.join().unwrap();
 }
+
+#[test]
+// Hash depends only on the ring buffer address, not on its mutable state.
+#[allow(clippy::mutable_key_type)]
+fn identity() {
+    let rb0 = Arc::new(SharedRb::<Heap<u8>>::new(4));
+    let rb1 = Arc::new(SharedRb::<Heap<u8>>::new(4));
+    let obs0 = Obs::new(rb0.clone());
+    let obs1 = Obs::new(rb1.clone());
+
+    let mut map = HashMap::new();
+    map.insert(obs0.clone(), 0);
+    map.insert(obs1.clone(), 1);
+    assert!(obs0 != obs1);
+    assert!(obs0 == Obs::new(rb0));
+    assert_eq!(map.get(&Obs::new(rb1)), Some(&1));
+}
+
+#[test]
+fn from_local() {
+    let mut rb = LocalRb::<Heap<i32>>::new(4);
+    rb.push_iter(0..4);
+    rb.skip(3);
+    rb.push_iter(4..7);
+
+    let rb = rb.into_shared();
+    assert_eq!(rb.capacity().get(), 4);
+    assert!(rb.iter().copied().eq(3..7));
+
+    let (mut prod, mut cons) = rb.split();
+    let items = thread::spawn(move || {
+        assert_eq!(prod.try_push(7), Err(7));
+        cons.pop_iter().collect::<Vec<_>>()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(items, [3, 4, 5, 6]);
+}
+
+#[test]
+fn notify() {
+    #[derive(Default)]
+    struct Counter {
+        pushes: AtomicUsize,
+        pops: AtomicUsize,
+    }
+
+    impl Notify for Counter {
+        fn on_push(&self) {
+            self.pushes.fetch_add(1, Ordering::Relaxed);
+        }
+        fn on_pop(&self) {
+            self.pops.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut rb = SharedRb::<Heap<i32>>::new(8);
+    rb.push_iter(0..2);
+    let rb = Arc::new(rb.with_notify(Counter::default()));
+    assert_eq!(rb.occupied_len(), 2);
+    let (mut prod, mut cons) = rb.clone().split();
+
+    assert_eq!(prod.push_slice(&[2, 3]), 2);
+    assert_eq!(prod.try_push(4), Ok(()));
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(rb.notify().pushes.load(Ordering::Relaxed), 2);
+    assert_eq!(rb.notify().pops.load(Ordering::Relaxed), 1);
+
+    let mut prod = prod.freeze();
+    assert_eq!(prod.try_push(5), Ok(()));
+    assert_eq!(rb.notify().pushes.load(Ordering::Relaxed), 2);
+    prod.commit();
+    assert_eq!(rb.notify().pushes.load(Ordering::Relaxed), 3);
+
+    // Syncs and drops without changes don't notify.
+    prod.sync();
+    cons.sync();
+    let cons = cons.freeze();
+    cons.commit();
+    drop(prod);
+    drop(cons);
+    assert_eq!(rb.notify().pushes.load(Ordering::Relaxed), 3);
+    assert_eq!(rb.notify().pops.load(Ordering::Relaxed), 1);
+
+    let rb = Arc::into_inner(rb).unwrap().with_notify(Counter::default());
+    assert_eq!(rb.occupied_len(), 5);
+    assert_eq!(rb.notify().pushes.load(Ordering::Relaxed), 0);
+}
@@ -70,3 +70,26 @@ fn skip_drop() {
     // Check that items are dropped
     assert_eq!(Rc::strong_count(&rc), 1);
 }
+
+#[test]
+fn skip_drop_wrapped() {
+    let rc = Rc::<()>::new(());
+
+    let mut rb = Rb::<Array<Rc<()>, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.try_push(rc.clone()).unwrap();
+    prod.try_push(rc.clone()).unwrap();
+    assert_eq!(cons.skip(2), 2);
+    for _ in 0..4 {
+        prod.try_push(rc.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(cons.occupied_len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    assert_eq!(cons.skip(4), 1);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
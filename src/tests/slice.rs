@@ -1,5 +1,5 @@
 use super::Rb;
-use crate::{storage::Array, traits::*, transfer};
+use crate::{storage::Array, traits::*, transfer, transfer_all, transfer_exact, transfer_map};
 
 #[test]
 fn push_pop_slice() {
@@ -95,3 +95,365 @@ fn move_slice_count() {
     assert_eq!(cons1.pop_slice(&mut tmp), 4);
     assert_eq!(tmp[0..4], [6, 7, 8, 9]);
 }
+
+#[test]
+fn pop_into_slices() {
+    use core::mem::MaybeUninit;
+
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut first = [MaybeUninit::<i32>::uninit(); 3];
+    let mut second = [MaybeUninit::<i32>::uninit(); 2];
+    assert_eq!(cons.pop_into_slices(&mut first, &mut second), 4);
+    assert!(cons.is_empty());
+    assert_eq!(first.map(|x| unsafe { x.assume_init() }), [2, 3, 4]);
+    assert_eq!(unsafe { second[0].assume_init() }, 5);
+}
+
+#[test]
+fn process_and_consume() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut chunks = [[0; 2]; 2];
+    let mut n = 0;
+    assert_eq!(
+        cons.process_and_consume(3, |chunk| {
+            chunks[n][..chunk.len()].copy_from_slice(chunk);
+            n += 1;
+        }),
+        3
+    );
+    assert_eq!(n, 2);
+    assert_eq!(chunks, [[2, 3], [4, 0]]);
+    assert!(cons.iter().copied().eq([5]));
+
+    assert_eq!(cons.process_and_consume(8, |chunk| assert_eq!(chunk, [5])), 1);
+    assert_eq!(cons.process_and_consume(8, |_| unreachable!()), 0);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec() {
+    use arrayvec::ArrayVec;
+
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut vec = ArrayVec::<i32, 6>::from([0, 1, 2, 3, 4, 5]);
+    assert_eq!(prod.push_from_arrayvec(&mut vec), 4);
+    assert_eq!(vec.as_slice(), [4, 5]);
+
+    let vec = cons.pop_into_arrayvec::<3>();
+    assert_eq!(vec.as_slice(), [0, 1, 2]);
+    let vec = cons.pop_into_arrayvec::<3>();
+    assert_eq!(vec.as_slice(), [3]);
+    assert!(cons.pop_into_arrayvec::<3>().is_empty());
+}
+
+#[test]
+fn lens() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.vacant_lens(), (4, 0));
+    assert_eq!(cons.occupied_lens(), (0, 0));
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.vacant_lens(), (1, 2));
+    assert_eq!(cons.occupied_lens(), (1, 0));
+
+    assert_eq!(prod.push_slice(&[3, 4]), 2);
+    assert_eq!(prod.vacant_lens(), (1, 0));
+    assert_eq!(cons.occupied_lens(), (2, 1));
+}
+
+#[test]
+fn peek_frame() {
+    let mut rb = Rb::<Array<u8, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    // Frame is a length byte followed by payload.
+    fn frame_len<C: Consumer<Item = u8>>(cons: &C) -> Option<usize> {
+        let (left, right) = cons.as_slices();
+        let len = 1 + *left.first()? as usize;
+        (left.len() + right.len() >= len).then_some(len)
+    }
+
+    assert_eq!(prod.push_slice(&[0, 0, 0, 0]), 4);
+    assert_eq!(cons.skip(4), 4);
+
+    assert_eq!(prod.push_slice(&[3, 1, 2]), 3);
+    assert_eq!(frame_len(&cons), None);
+    assert_eq!(cons.occupied_len(), 3);
+
+    assert_eq!(prod.push_slice(&[3]), 1);
+    assert_eq!(frame_len(&cons), Some(4));
+    let mut tmp = [0; 4];
+    assert_eq!(cons.peek_slice(&mut tmp), 4);
+    assert_eq!(tmp, [3, 1, 2, 3]);
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[test]
+fn pop_slices() {
+    let mut rb = Rb::<Array<i32, 5>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 0, 0]), 3);
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(prod.push_slice(&[0, 1, 2, 3, 4]), 5);
+
+    let (mut a, mut b, mut c) = ([-1; 1], [-1; 3], [-1; 2]);
+    assert_eq!(cons.pop_slices(&mut [&mut a, &mut [], &mut b, &mut c]), 5);
+    assert_eq!((a, b, c), ([0], [1, 2, 3], [4, -1]));
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.push_slice(&[5, 6]), 2);
+    let (mut a, mut b) = ([-1; 1], [-1; 3]);
+    assert_eq!(cons.pop_slices(&mut [&mut a, &mut b]), 2);
+    assert_eq!((a, b), ([5], [6, -1, -1]));
+}
+
+#[test]
+fn peek_slice_at() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[0, 1, 2]);
+    cons.skip(2);
+    prod.push_slice(&[3, 4, 5]);
+    assert_eq!(cons.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+    let mut buf = [0; 2];
+    assert_eq!(cons.peek_slice_at(1, &mut buf), 2);
+    assert_eq!(buf, [3, 4]);
+    assert_eq!(cons.peek_slice_at(3, &mut buf), 1);
+    assert_eq!(buf[0], 5);
+    assert_eq!(cons.peek_slice_at(4, &mut buf), 0);
+    assert_eq!(cons.peek_slice_at(10, &mut buf), 0);
+
+    let mut buf = [0; 8];
+    assert_eq!(cons.peek_slice_at(0, &mut buf), 4);
+    assert_eq!(buf[..4], [2, 3, 4, 5]);
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[test]
+fn move_exact() {
+    let mut rb0 = Rb::<Array<i32, 4>>::default();
+    let mut rb1 = Rb::<Array<i32, 4>>::default();
+    let (mut prod0, mut cons0) = rb0.split_ref();
+    let (mut prod1, mut cons1) = rb1.split_ref();
+
+    prod0.push_slice(&[0, 1, 2]);
+    prod1.push_slice(&[10, 11]);
+    assert!(!transfer_exact(&mut cons0, &mut prod1, 3));
+    assert!(!transfer_exact(&mut cons0, &mut prod1, 4));
+    assert_eq!(cons0.occupied_len(), 3);
+    assert_eq!(cons1.occupied_len(), 2);
+
+    assert!(transfer_exact(&mut cons0, &mut prod1, 2));
+    assert_eq!(cons0.as_slices().0, &[2]);
+    assert!(cons1.pop_iter().eq([10, 11, 0, 1]));
+    assert!(transfer_exact(&mut cons0, &mut prod1, 0));
+}
+
+#[test]
+fn move_map() {
+    let mut rb0 = Rb::<Array<i32, 4>>::default();
+    let mut rb1 = Rb::<Array<u8, 3>>::default();
+    let (mut prod0, mut cons0) = rb0.split_ref();
+    let (mut prod1, mut cons1) = rb1.split_ref();
+
+    prod0.push_slice(&[1, 2, 3, 4]);
+    assert_eq!(transfer_map(&mut cons0, &mut prod1, Some(1), |x| x as u8), 1);
+    assert_eq!(transfer_map(&mut cons0, &mut prod1, None, |x| (x * 10) as u8), 2);
+    assert_eq!(cons0.as_slices().0, &[4]);
+    assert!(cons1.pop_iter().eq([1, 20, 30]));
+
+    assert_eq!(transfer_map(&mut cons0, &mut prod1, None, |x| x as u8), 1);
+    assert_eq!(transfer_map(&mut cons0, &mut prod1, None, |x| x as u8), 0);
+    assert!(cons0.is_empty());
+    assert_eq!(cons1.try_pop(), Some(4));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_vec() {
+    use alloc::{string::String, vec};
+
+    let mut rb = Rb::<Array<String, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert!(cons.to_vec().is_empty());
+
+    prod.push_iter(["a", "b", "c"].into_iter().map(String::from));
+    cons.skip(2);
+    prod.push_iter(["d", "e"].into_iter().map(String::from));
+    assert_eq!(cons.to_vec(), vec!["c", "d", "e"]);
+    assert_eq!(cons.occupied_len(), 3);
+}
+
+#[test]
+fn eq_contents() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert!(cons.eq_contents(&[]));
+
+    prod.push_slice(&[0, 1, 2]);
+    cons.skip(2);
+    prod.push_slice(&[3, 4]);
+    assert!(cons.eq_contents(&[2, 3, 4]));
+    assert!(!cons.eq_contents(&[2, 3]));
+    assert!(!cons.eq_contents(&[2, 3, 4, 5]));
+    assert!(!cons.eq_contents(&[2, 0, 4]));
+    assert!(!cons.eq_contents(&[0, 3, 4]));
+}
+
+#[test]
+fn push_array() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_array([0, 1, 2]).unwrap();
+    assert_eq!(prod.push_array([3, 4]), Err([3, 4]));
+    assert_eq!(cons.occupied_len(), 3);
+
+    assert_eq!(cons.try_pop(), Some(0));
+    prod.push_array([3, 4]).unwrap();
+    prod.push_array([]).unwrap();
+    assert!(cons.pop_iter().eq(1..5));
+}
+
+#[test]
+fn pop_array() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.push_slice(&[0, 1, 2]);
+    assert_eq!(cons.pop_array::<4>(), None);
+    assert_eq!(cons.pop_array(), Some([0, 1]));
+    assert_eq!(cons.pop_array(), Some([]));
+
+    prod.push_slice(&[3, 4, 5]);
+    assert_eq!(cons.pop_array(), Some([2, 3, 4, 5]));
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn push_pop_ne() {
+    let mut rb = Rb::<Array<u8, 12>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0; 3]), 3);
+    assert_eq!(cons.skip(3), 3);
+
+    assert_eq!(prod.push_u16_ne(0x0102), Ok(()));
+    assert_eq!(prod.push_u32_ne(0x03040506), Ok(()));
+    assert_eq!(prod.push_u64_ne(0x0708090a0b0c0d0e), Err(0x0708090a0b0c0d0e));
+    assert_eq!(prod.push_u16_ne(0x0f10), Ok(()));
+    assert_eq!(cons.occupied_len(), 8);
+
+    assert_eq!(cons.pop_u16_ne(), Some(0x0102));
+    assert_eq!(cons.pop_u64_ne(), None);
+    assert_eq!(cons.pop_u32_ne(), Some(0x03040506));
+    assert_eq!(cons.pop_u32_ne(), None);
+    assert_eq!(cons.occupied_len(), 2);
+    assert_eq!(cons.pop_u16_ne(), Some(0x0f10));
+
+    assert_eq!(prod.push_u64_ne(u64::MAX - 1), Ok(()));
+    assert_eq!(cons.pop_u64_ne(), Some(u64::MAX - 1));
+}
+
+#[test]
+fn push_slice_all_or_none() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert!(prod.push_slice_all_or_none(&[0, 1, 2]));
+    assert!(!prod.push_slice_all_or_none(&[3, 4]));
+    assert_eq!(cons.occupied_len(), 3);
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert!(prod.push_slice_all_or_none(&[3, 4]));
+    assert!(prod.push_slice_all_or_none(&[]));
+    assert!(cons.pop_iter().eq(1..5));
+}
+
+#[test]
+fn pop_slice_exact() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let mut buf = [0; 3];
+
+    assert_eq!(prod.push_slice(&[0, 1]), 2);
+    assert!(!cons.pop_slice_exact(&mut buf));
+    assert_eq!(cons.occupied_len(), 2);
+    assert_eq!(buf, [0; 3]);
+
+    assert_eq!(prod.push_slice(&[2, 3]), 2);
+    assert!(cons.pop_slice_exact(&mut buf));
+    assert_eq!(buf, [0, 1, 2]);
+    assert!(cons.pop_slice_exact(&mut []));
+    assert!(cons.iter().copied().eq([3]));
+}
+
+#[test]
+fn push_slice_with() {
+    use core::ops::ControlFlow;
+
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let mut out = [0; 8];
+    let mut len = 0;
+
+    let input = [0, 1, 2, 3, 4, 5, 6, 7];
+    let count = prod.push_slice_with(&input, || {
+        len += cons.pop_slice(&mut out[len..]);
+        ControlFlow::Continue(())
+    });
+    assert_eq!(count, 8);
+    len += cons.pop_slice(&mut out[len..]);
+    assert_eq!(out[..len], input);
+
+    let mut calls = 0;
+    let count = prod.push_slice_with(&input, || {
+        calls += 1;
+        ControlFlow::Break(())
+    });
+    assert_eq!((count, calls), (3, 1));
+}
+
+#[test]
+fn move_all() {
+    let mut rb0 = Rb::<Array<i32, 5>>::default();
+    let mut rb1 = Rb::<Array<i32, 4>>::default();
+    let (mut prod0, mut cons0) = rb0.split_ref();
+    let (mut prod1, mut cons1) = rb1.split_ref();
+
+    prod0.push_iter(0..4);
+    cons0.skip(4);
+    prod0.push_iter(0..5);
+    assert!(!cons0.as_slices().1.is_empty());
+
+    prod1.push_iter(0..3);
+    cons1.skip(3);
+    let (left, right) = prod1.vacant_slices();
+    assert_eq!((left.len(), right.len()), (1, 3));
+
+    assert_eq!(transfer_all(&mut cons0, &mut prod1), 4);
+    assert!(cons1.pop_iter().eq(0..4));
+    assert_eq!(transfer_all(&mut cons0, &mut prod1), 1);
+    assert_eq!(cons1.try_pop(), Some(4));
+    assert_eq!(transfer_all(&mut cons0, &mut prod1), 0);
+}
@@ -47,3 +47,35 @@ fn basic() {
 
     assert!(cons.try_pop().is_none());
 }
+
+#[test]
+fn max_capacity() {
+    const CAP: usize = usize::MAX / 4;
+    let rb = HeapRb::<()>::new(CAP);
+    let modulus = 2 * CAP;
+
+    // Drive indices around the modulus many times with uneven steps so that they get close to its boundary.
+    for step in [CAP, CAP - 1, 1, CAP - 2, CAP] {
+        for _ in 0..8 {
+            unsafe { rb.advance_write_index(step) };
+            assert_eq!(rb.occupied_len(), step);
+            assert_eq!(rb.vacant_len(), CAP - step);
+            assert!(rb.write_index() < modulus);
+
+            let (first, second) = rb.occupied_ranges();
+            assert_eq!(first.len() + second.len(), step);
+            let (first, second) = rb.vacant_ranges();
+            assert_eq!(first.len() + second.len(), CAP - step);
+
+            unsafe { rb.advance_read_index(step) };
+            assert!(rb.is_empty());
+            assert!(rb.read_index() < modulus);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn too_large_capacity() {
+    HeapRb::<()>::new(usize::MAX / 4 + 1);
+}
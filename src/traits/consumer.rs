@@ -2,10 +2,20 @@ use super::{
     observer::{DelegateObserver, Observer},
     utils::modulus,
 };
-use crate::utils::{move_uninit_slice, slice_as_uninit_mut, slice_assume_init_mut, slice_assume_init_ref};
-use core::{iter::Chain, mem::MaybeUninit, ptr, slice};
+use crate::utils::{
+    add_mod, array_assume_init, move_uninit_slice, slice_as_uninit_mut, slice_assume_init_mut, slice_assume_init_ref, uninit_array,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    hash::Hasher,
+    iter::Chain,
+    mem::{self, MaybeUninit},
+    ops::{ControlFlow, Deref, DerefMut},
+    ptr, slice,
+};
 #[cfg(feature = "std")]
-use std::io::{self, Write};
+use std::io::{self, IoSlice, Write};
 
 /// Consumer part of ring buffer.
 pub trait Consumer: Observer {
@@ -25,8 +35,26 @@ pub trait Consumer: Observer {
     /// First `count` items in occupied memory must be moved out or dropped.
     ///
     /// Must not be called concurrently.
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn advance_read_index(&self, count: usize) {
-        self.set_read_index((self.read_index() + count) % modulus(self));
+        self.set_read_index(add_mod(self.read_index(), count, modulus(self)));
+    }
+
+    /// Same as [`Self::advance_read_index`] but checks that `count` doesn't exceed the number of occupied items.
+    ///
+    /// Returns `Err` containing current number of occupied items and doesn't move the pointer if it does.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::advance_read_index`].
+    unsafe fn advance_read_index_checked(&self, count: usize) -> Result<(), usize> {
+        let occupied = self.occupied_len();
+        if count > occupied {
+            return Err(occupied);
+        }
+        self.advance_read_index(count);
+        Ok(())
     }
 
     /// Provides a direct access to the ring buffer occupied memory.
@@ -42,6 +70,8 @@ pub trait Consumer: Observer {
     ///
     /// *This method must be followed by [`Self::advance_read_index`] call with the number of items being removed previously as argument.*
     /// *No other mutating calls allowed before that.*
+    ///
+    /// To read items without removing them use safe [`Self::as_slices`] instead.
     fn occupied_slices(&self) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         unsafe { self.unsafe_slices(self.read_index(), self.write_index()) }
     }
@@ -53,11 +83,38 @@ pub trait Consumer: Observer {
     /// # Safety
     ///
     /// When some item is replaced with uninitialized value then it must not be read anymore.
+    ///
+    /// To modify items in place use safe [`Self::as_mut_slices`] instead.
     unsafe fn occupied_slices_mut(&mut self) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.unsafe_slices_mut(self.read_index(), self.write_index())
     }
 
+    /// Returns lengths of the slices returned by [`Self::occupied_slices`].
+    fn occupied_lens(&self) -> (usize, usize) {
+        let (left, right) = self.occupied_slices();
+        (left.len(), right.len())
+    }
+
     /// Returns a pair of slices which contain, in order, the contents of the ring buffer.
+    ///
+    /// This is a safe counterpart of [`Self::occupied_slices`] because all occupied items are initialized.
+    ///
+    /// Items are not removed, so it can be used to peek the contents, e.g. to check whether a complete frame is received.
+    /// To copy items without removing them use [`Self::peek_slice`].
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 4>>::default();
+    ///
+    /// assert_eq!(rb.push_iter(0..4), 4);
+    /// assert_eq!(rb.skip(2), 2);
+    /// assert_eq!(rb.push_iter(4..6), 2);
+    ///
+    /// assert_eq!(rb.as_slices(), (&[2, 3][..], &[4, 5][..]));
+    /// # }
+    /// ```
     #[inline]
     fn as_slices(&self) -> (&[Self::Item], &[Self::Item]) {
         unsafe {
@@ -66,6 +123,21 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Returns a pair of slices which contain, in order, the items pushed since the write index was equal to `saved_write_index`.
+    ///
+    /// Items that were already removed from the ring buffer are not included.
+    /// See [`Observer::occupied_since`] for details.
+    fn as_slices_since(&self, saved_write_index: usize) -> (&[Self::Item], &[Self::Item]) {
+        let modulus = modulus(self);
+        let (read, write) = (self.read_index(), self.write_index());
+        let since = (modulus.get() + write - saved_write_index % modulus) % modulus;
+        let count = usize::min(since, (modulus.get() + write - read) % modulus);
+        unsafe {
+            let (left, right) = self.unsafe_slices((modulus.get() + write - count) % modulus, write);
+            (slice_assume_init_ref(left), slice_assume_init_ref(right))
+        }
+    }
+
     /// Returns a pair of mutable slices which contain, in order, the contents of the ring buffer.
     #[inline]
     fn as_mut_slices(&mut self) -> (&mut [Self::Item], &mut [Self::Item]) {
@@ -108,9 +180,39 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Returns the index of the eldest item satisfying the predicate `f` without removing anything.
+    ///
+    /// Index `0` corresponds to the eldest item, so the result may be passed to [`Self::skip`] or used to size a buffer for [`Self::pop_slice`].
+    fn position<F: FnMut(&Self::Item) -> bool>(&self, mut f: F) -> Option<usize> {
+        let (left, right) = self.as_slices();
+        match left.iter().position(&mut f) {
+            Some(i) => Some(i),
+            None => right.iter().position(f).map(|i| left.len() + i),
+        }
+    }
+    /// Returns the index of the eldest byte equal to `byte` without removing anything.
+    ///
+    /// Same as [`Self::position`] but uses `memchr` crate if `memchr` feature is enabled.
+    fn find_byte(&self, byte: u8) -> Option<usize>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let (left, right) = self.as_slices();
+        #[cfg(feature = "memchr")]
+        let find = |slice: &[u8]| memchr::memchr(byte, slice);
+        #[cfg(not(feature = "memchr"))]
+        let find = |slice: &[u8]| slice.iter().position(|b| *b == byte);
+        match find(left) {
+            Some(i) => Some(i),
+            None => find(right).map(|i| left.len() + i),
+        }
+    }
+
     /// Removes the eldest item from the ring buffer and returns it.
     ///
     /// Returns `None` if the ring buffer is empty.
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     fn try_pop(&mut self) -> Option<Self::Item> {
         if !self.is_empty() {
             let elem = unsafe { self.occupied_slices().0.get_unchecked(0).assume_init_read() };
@@ -121,6 +223,23 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Returns a guard referencing the eldest item that removes the item from the ring buffer when the guard is dropped.
+    ///
+    /// The item is processed in place and dropped without being moved out of the ring buffer.
+    ///
+    /// Returns `None` if the ring buffer is empty.
+    fn try_pop_ref(&mut self) -> Option<PopRefGuard<'_, Self>> {
+        PopRefGuard::new(self)
+    }
+
+    /// Returns a guard providing in-place access to occupied items.
+    ///
+    /// Items are removed from the ring buffer only on [`ReadGuard::commit`] call.
+    /// If the guard is dropped without committing then nothing is removed.
+    fn read_guard(&mut self) -> ReadGuard<'_, Self> {
+        ReadGuard::new(self)
+    }
+
     /// Returns the reference to the eldest item without removing it from the buffer.
     ///
     /// Returns `None` if the ring buffer is empty.
@@ -165,6 +284,27 @@ pub trait Consumer: Observer {
         self.peek_slice_uninit(unsafe { slice_as_uninit_mut(elems) })
     }
 
+    /// Copies items from the ring buffer to a slice starting at `offset` from the eldest item without removing them.
+    ///
+    /// First `offset` items are skipped.
+    /// Returns a number of items being copied, it is zero if `offset` is not less than number of items in the ring buffer.
+    fn peek_slice_at(&self, offset: usize, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.as_slices();
+        let (left, right) = if offset < left.len() {
+            (&left[offset..], right)
+        } else {
+            (&[][..], &right[usize::min(offset - left.len(), right.len())..])
+        };
+        let left_count = usize::min(elems.len(), left.len());
+        elems[..left_count].copy_from_slice(&left[..left_count]);
+        let right_count = usize::min(elems.len() - left_count, right.len());
+        elems[left_count..(left_count + right_count)].copy_from_slice(&right[..right_count]);
+        left_count + right_count
+    }
+
     /// Removes items from the ring buffer and writes them into an uninit slice.
     ///
     /// Returns count of items been removed.
@@ -174,6 +314,64 @@ pub trait Consumer: Observer {
         count
     }
 
+    /// Removes exactly `N` items from the ring buffer and returns them as an array.
+    ///
+    /// Returns `None` and removes nothing if there are less than `N` items in the ring buffer.
+    fn pop_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        if self.occupied_len() < N {
+            return None;
+        }
+        let mut elems = uninit_array();
+        let count = self.pop_slice_uninit(&mut elems);
+        debug_assert_eq!(count, N);
+        Some(unsafe { array_assume_init(elems) })
+    }
+
+    /// Removes 2 bytes from the ring buffer and combines them into `u16` in native byte order.
+    ///
+    /// Returns `None` and removes nothing if there are less than 2 bytes in the ring buffer.
+    fn pop_u16_ne(&mut self) -> Option<u16>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        self.pop_array().map(u16::from_ne_bytes)
+    }
+
+    /// Removes 4 bytes from the ring buffer and combines them into `u32` in native byte order.
+    ///
+    /// Returns `None` and removes nothing if there are less than 4 bytes in the ring buffer.
+    fn pop_u32_ne(&mut self) -> Option<u32>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        self.pop_array().map(u32::from_ne_bytes)
+    }
+
+    /// Removes 8 bytes from the ring buffer and combines them into `u64` in native byte order.
+    ///
+    /// Returns `None` and removes nothing if there are less than 8 bytes in the ring buffer.
+    fn pop_u64_ne(&mut self) -> Option<u64>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        self.pop_array().map(u64::from_ne_bytes)
+    }
+
+    /// Removes items from the ring buffer and writes them into a pair of uninit slices.
+    ///
+    /// `first` is filled before `second`, so it is possible to write directly to a destination that wraps around itself
+    /// (e.g. vacant slices of another ring buffer).
+    ///
+    /// Returns total count of items been removed.
+    fn pop_into_slices(&mut self, first: &mut [MaybeUninit<Self::Item>], second: &mut [MaybeUninit<Self::Item>]) -> usize {
+        let count = self.pop_slice_uninit(first);
+        if count < first.len() {
+            count
+        } else {
+            count + self.pop_slice_uninit(second)
+        }
+    }
+
     /// Removes items from the ring buffer and writes them into a slice.
     ///
     /// Returns count of items been removed.
@@ -184,11 +382,123 @@ pub trait Consumer: Observer {
         self.pop_slice_uninit(unsafe { slice_as_uninit_mut(elems) })
     }
 
+    /// Fills the whole slice with items removed from the ring buffer only if there is enough items for it.
+    ///
+    /// Returns `false` and removes nothing otherwise, so a fixed-size record is never torn.
+    fn pop_slice_exact(&mut self, elems: &mut [Self::Item]) -> bool
+    where
+        Self::Item: Copy,
+    {
+        if self.occupied_len() < elems.len() {
+            return false;
+        }
+        let count = self.pop_slice(elems);
+        debug_assert_eq!(count, elems.len());
+        true
+    }
+
+    /// Removes items from the ring buffer and writes them into multiple slices in order.
+    ///
+    /// Next slice is filled only when the previous one is full.
+    /// The read index is advanced once by the total number of items.
+    ///
+    /// Returns count of items been removed.
+    fn pop_slices(&mut self, dsts: &mut [&mut [Self::Item]]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let (mut left, mut right) = self.as_slices();
+        let mut count = 0;
+        'outer: for dst in dsts.iter_mut() {
+            let mut dst = &mut dst[..];
+            while !dst.is_empty() {
+                if left.is_empty() {
+                    if right.is_empty() {
+                        break 'outer;
+                    }
+                    left = mem::take(&mut right);
+                }
+                let n = usize::min(dst.len(), left.len());
+                let (src_head, src_tail) = left.split_at(n);
+                let (dst_head, dst_tail) = dst.split_at_mut(n);
+                dst_head.copy_from_slice(src_head);
+                (left, dst) = (src_tail, dst_tail);
+                count += n;
+            }
+        }
+        unsafe { self.advance_read_index(count) };
+        count
+    }
+
+    /// Removes at most `N` eldest items from the ring buffer and returns them in a stack-allocated vector.
+    ///
+    /// Returned vector may be partially filled or empty if there are not enough items in the ring buffer.
+    #[cfg(feature = "arrayvec")]
+    fn pop_into_arrayvec<const N: usize>(&mut self) -> arrayvec::ArrayVec<Self::Item, N> {
+        let mut vec = arrayvec::ArrayVec::new();
+        while !vec.is_full() {
+            match self.try_pop() {
+                Some(item) => vec.push(item),
+                None => break,
+            }
+        }
+        vec
+    }
+
+    /// Checks whether items of the ring buffer are equal to `other` slice items in order from the eldest to the latest.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::{LocalRb, storage::Array, traits::*};
+    /// # fn main() {
+    /// let mut rb = LocalRb::<Array<i32, 3>>::default();
+    /// rb.push_slice(&[1, 2, 3]);
+    /// rb.skip(1);
+    /// rb.try_push(4).unwrap();
+    ///
+    /// assert!(rb.eq_contents(&[2, 3, 4]));
+    /// # }
+    /// ```
+    fn eq_contents(&self, other: &[Self::Item]) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        let (left, right) = self.as_slices();
+        left.len() + right.len() == other.len() && *left == other[..left.len()] && *right == other[left.len()..]
+    }
+
+    /// Clones items of the ring buffer into a newly allocated vector without removing them.
+    ///
+    /// Items are placed in order from the eldest to the latest.
+    #[cfg(feature = "alloc")]
+    fn to_vec(&self) -> Vec<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        let (left, right) = self.as_slices();
+        let mut vec = Vec::with_capacity(left.len() + right.len());
+        vec.extend_from_slice(left);
+        vec.extend_from_slice(right);
+        vec
+    }
+
     /// Returns an iterator that removes items one by one from the ring buffer.
-    fn pop_iter(&mut self) -> PopIter<Self> {
+    ///
+    /// Only items that are present in the ring buffer at the moment of the call are yielded,
+    /// items pushed after that are left in the ring buffer.
+    /// Yielded items are removed when the iterator is dropped or [`PopIter::commit`] is called.
+    fn pop_iter(&mut self) -> PopIter<'_, Self> {
         PopIter::new(self)
     }
 
+    /// Returns an iterator that removes all items from the ring buffer yielding them from the eldest to the latest.
+    ///
+    /// Unlike [`Self::pop_iter`], items that haven't been yielded are dropped when the iterator is dropped.
+    /// Items pushed after the call are left in the ring buffer.
+    fn drain(&mut self) -> Drain<'_, Self> {
+        Drain { iter: self.pop_iter() }
+    }
+
     /// Returns a front-to-back iterator containing references to items in the ring buffer.
     ///
     /// This iterator does not remove items out of the ring buffer.
@@ -209,6 +519,8 @@ pub trait Consumer: Observer {
     ///
     /// If there is no concurring producer activity then exactly `min(count, Self::len())` items are removed.
     ///
+    /// Items that don't need to be dropped (e.g. `Copy` ones) aren't touched, only the read index is advanced.
+    ///
     /// Returns the number of deleted items.
     ///
     /// ```
@@ -227,8 +539,10 @@ pub trait Consumer: Observer {
     fn skip(&mut self, count: usize) -> usize {
         unsafe {
             let (left, right) = self.occupied_slices_mut();
-            for elem in left.iter_mut().chain(right.iter_mut()).take(count) {
-                ptr::drop_in_place(elem.as_mut_ptr());
+            if mem::needs_drop::<Self::Item>() {
+                for elem in left.iter_mut().chain(right.iter_mut()).take(count) {
+                    ptr::drop_in_place(elem.as_mut_ptr());
+                }
             }
             let actual_count = usize::min(count, left.len() + right.len());
             self.advance_read_index(actual_count);
@@ -251,6 +565,118 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Retains only the items for which `f` returns `true`, others are removed and dropped.
+    ///
+    /// Items are visited exactly once in order from the eldest to the most recent one (like `Vec::retain`),
+    /// so `f` may be stateful (e.g. keep only the first occurrence of each item).
+    ///
+    /// Order of the retained items is preserved.
+    /// Retained items are finally moved towards the most recent one, so only the read index is changed
+    /// and this method may be called while the producer is active.
+    fn retain<F: FnMut(&Self::Item) -> bool>(&mut self, mut f: F) {
+        let this: &Self = self;
+        let (left, right) = unsafe { this.unsafe_slices_mut(this.read_index(), this.write_index()) };
+        let len = left.len() + right.len();
+        let mut guard = RetainGuard {
+            inner: this,
+            left: left.as_mut_ptr().cast(),
+            left_len: left.len(),
+            right: right.as_mut_ptr().cast(),
+            kept: 0,
+            removed: 0,
+        };
+        while guard.kept + guard.removed < len {
+            let index = guard.kept + guard.removed;
+            let item = guard.slot(index);
+            if f(unsafe { &*item }) {
+                if guard.removed > 0 {
+                    unsafe { ptr::copy_nonoverlapping(item, guard.slot(guard.kept), 1) };
+                }
+                guard.kept += 1;
+            } else {
+                guard.removed += 1;
+                unsafe { ptr::drop_in_place(item) };
+            }
+        }
+    }
+
+    /// Passes the eldest items one by one to `f` and removes them while it returns [`ControlFlow::Continue`].
+    ///
+    /// `f` may modify the item before it is removed and dropped.
+    /// When `f` returns [`ControlFlow::Break`] the item is left in the ring buffer and iteration stops.
+    ///
+    /// Returns the number of removed items.
+    fn consume_front_while<F: FnMut(&mut Self::Item) -> ControlFlow<()>>(&mut self, mut f: F) -> usize {
+        let mut count = 0;
+        while let Some(item) = self.first_mut() {
+            if f(item).is_break() {
+                break;
+            }
+            unsafe {
+                ptr::drop_in_place(item);
+                self.advance_read_index(1);
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Passes at most `max` eldest items to `f` as contiguous chunks and then removes them from the buffer.
+    ///
+    /// `f` is called once for each non-empty chunk (at most twice), so items can be processed in place without copying.
+    ///
+    /// Returns the number of removed items.
+    fn process_and_consume<F: FnMut(&[Self::Item])>(&mut self, max: usize, mut f: F) -> usize {
+        let (left, right) = self.as_slices();
+        let left = &left[..usize::min(max, left.len())];
+        let right = &right[..usize::min(max - left.len(), right.len())];
+        for chunk in [left, right] {
+            if !chunk.is_empty() {
+                f(chunk);
+            }
+        }
+        let count = left.len() + right.len();
+        self.skip(count)
+    }
+
+    /// Feeds all bytes stored in the ring buffer to the `hasher` in order without removing them.
+    ///
+    /// Occupied slices are passed to [`Hasher::write`] one by one.
+    /// The result does not depend on where the data wraps around only for hashers processing bytes as a continuous stream
+    /// (like CRC or most cryptographic hashes), while other hashers may take slice boundaries into account.
+    fn digest<H: Hasher>(&self, hasher: &mut H)
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let (left, right) = self.as_slices();
+        hasher.write(left);
+        hasher.write(right);
+    }
+
+    #[cfg(feature = "crc")]
+    /// Computes CRC32 checksum of all bytes stored in the ring buffer without removing them.
+    fn checksum_crc32(&self) -> u32
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let mut hasher = crc32fast::Hasher::new();
+        self.digest(&mut hasher);
+        hasher.finalize()
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns a reader that reports the end of stream only when the producer is closed.
+    ///
+    /// Unlike [`io::Read`] implementation of the consumer, that returns `Err(WouldBlock)` whenever the ring buffer is empty,
+    /// the reader returns `Ok(0)` if the ring buffer is empty and the producer was dropped (or closed),
+    /// and `Err(WouldBlock)` only if the producer still exists.
+    fn eof_reader(&mut self) -> EofReader<'_, Self>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        EofReader { inner: self }
+    }
+
     #[cfg(feature = "std")]
     /// Removes at most first `count` bytes from the ring buffer and writes them into a [`Write`] instance.
     /// If `count` is `None` then as much as possible bytes will be written.
@@ -260,7 +686,7 @@ pub trait Consumer: Observer {
     /// + `None`: ring buffer is full or `count` is `0`. In this case `write` isn't called at all.
     /// + `Some(Ok(n))`: `write` succeeded. `n` is number of bytes been written. `n == 0` means that `write` also returned `0`.
     /// + `Some(Err(e))`: `write` is failed and `e` is original error. In this case it is guaranteed that no items was written to the writer.
-    ///    To achieve this we write only one contiguous slice at once. So this call may write less than `occupied_len` items even if the writer is ready to get more.
+    ///   To achieve this we write only one contiguous slice at once. So this call may write less than `occupied_len` items even if the writer is ready to get more.
     fn write_into<S: Write>(&mut self, writer: &mut S, count: Option<usize>) -> Option<io::Result<usize>>
     where
         Self: Consumer<Item = u8>,
@@ -280,6 +706,30 @@ pub trait Consumer: Observer {
         unsafe { self.advance_read_index(write_count) };
         Some(Ok(write_count))
     }
+
+    #[cfg(feature = "std")]
+    /// Writes bytes from both occupied slices of the ring buffer into a [`Write`] instance at once using [`Write::write_vectored`]
+    /// and removes written bytes from the ring buffer.
+    ///
+    /// Unlike [`Self::write_into`] this may flush the whole ring buffer with a single call even if its contents wrap around the end of the storage.
+    ///
+    /// Returns number of bytes been written. If the ring buffer is empty then `write_vectored` isn't called at all and `Ok(0)` is returned.
+    /// In case of error no items are removed from the ring buffer.
+    fn write_vectored_to<S: Write>(&mut self, writer: &mut S) -> io::Result<usize>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let (left, right) = self.as_slices();
+        let count = left.len() + right.len();
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let write_count = writer.write_vectored(&[IoSlice::new(left), IoSlice::new(right)])?;
+        assert!(write_count <= count);
+        unsafe { self.advance_read_index(write_count) };
+        Ok(write_count)
+    }
 }
 
 /// Owning ring buffer iterator.
@@ -343,6 +793,7 @@ impl<'a, C: Consumer + ?Sized> PopIter<'a, C> {
     /// Send information about removed items to the ring buffer.
     pub fn commit(&mut self) {
         unsafe { self.inner.advance_read_index(self.count) };
+        self.len -= self.count;
         self.count = 0;
     }
 }
@@ -366,6 +817,177 @@ impl<'a, C: Consumer> Iterator for PopIter<'a, C> {
 
 impl<'a, C: Consumer> ExactSizeIterator for PopIter<'a, C> {}
 
+/// An iterator that removes all items from the ring buffer, dropping those that haven't been yielded.
+///
+/// Created by [`Consumer::drain`].
+pub struct Drain<'a, C: Consumer + ?Sized> {
+    iter: PopIter<'a, C>,
+}
+
+impl<'a, C: Consumer + ?Sized> Drop for Drain<'a, C> {
+    fn drop(&mut self) {
+        for item in &mut self.iter.iter {
+            self.iter.count += 1;
+            drop(unsafe { item.assume_init_read() });
+        }
+    }
+}
+
+impl<'a, C: Consumer> Iterator for Drain<'a, C> {
+    type Item = C::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, C: Consumer> ExactSizeIterator for Drain<'a, C> {}
+
+/// Reader distinguishing an empty ring buffer from the end of stream.
+///
+/// Created by [`Consumer::eof_reader`].
+#[cfg(feature = "std")]
+pub struct EofReader<'a, C: Consumer<Item = u8> + ?Sized> {
+    inner: &'a mut C,
+}
+
+#[cfg(feature = "std")]
+impl<'a, C: Consumer<Item = u8> + ?Sized> io::Read for EofReader<'a, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.pop_slice(buf);
+        if n == 0 && !buf.is_empty() && self.inner.write_is_held() {
+            Err(io::ErrorKind::WouldBlock.into())
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+/// Mutable reference to the eldest item that removes the item from the ring buffer when dropped.
+pub struct PopRefGuard<'a, C: Consumer + ?Sized> {
+    inner: &'a C,
+    item: &'a mut MaybeUninit<C::Item>,
+}
+
+impl<'a, C: Consumer + ?Sized> PopRefGuard<'a, C> {
+    /// Create a guard referencing the eldest item.
+    ///
+    /// Returns `None` if the ring buffer is empty.
+    pub fn new(inner: &'a mut C) -> Option<Self> {
+        let inner: &'a C = inner;
+        let (left, _) = unsafe { inner.unsafe_slices_mut(inner.read_index(), inner.write_index()) };
+        Some(Self {
+            inner,
+            item: left.first_mut()?,
+        })
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Deref for PopRefGuard<'a, C> {
+    type Target = C::Item;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.item.assume_init_ref() }
+    }
+}
+impl<'a, C: Consumer + ?Sized> DerefMut for PopRefGuard<'a, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.item.assume_init_mut() }
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Drop for PopRefGuard<'a, C> {
+    fn drop(&mut self) {
+        unsafe {
+            self.item.assume_init_drop();
+            self.inner.advance_read_index(1);
+        }
+    }
+}
+
+/// Guard providing in-place access to the occupied items of the ring buffer.
+///
+/// Created by [`Consumer::read_guard`].
+pub struct ReadGuard<'a, C: Consumer + ?Sized> {
+    inner: &'a mut C,
+}
+
+impl<'a, C: Consumer + ?Sized> ReadGuard<'a, C> {
+    /// Create a guard.
+    pub fn new(inner: &'a mut C) -> Self {
+        Self { inner }
+    }
+
+    /// Pair of slices of occupied items, the second one may be empty.
+    ///
+    /// Items are placed in order from the eldest to the latest.
+    pub fn as_slices(&self) -> (&[C::Item], &[C::Item]) {
+        self.inner.as_slices()
+    }
+    /// Mutable version of [`Self::as_slices`].
+    pub fn as_mut_slices(&mut self) -> (&mut [C::Item], &mut [C::Item]) {
+        self.inner.as_mut_slices()
+    }
+
+    /// Number of occupied items.
+    pub fn len(&self) -> usize {
+        self.inner.occupied_len()
+    }
+    /// Whether there are no occupied items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove first `count` items from the ring buffer and drop them.
+    ///
+    /// *Panics if `count` is greater than [`Self::len`].*
+    pub fn commit(self, count: usize) {
+        assert!(count <= self.len());
+        self.inner.skip(count);
+    }
+}
+
+/// Moves unprocessed items over the removed ones and advances the read index when dropped (even on panic).
+struct RetainGuard<'a, C: Consumer + ?Sized> {
+    inner: &'a C,
+    left: *mut C::Item,
+    left_len: usize,
+    right: *mut C::Item,
+    /// Number of retained items, they are placed at the beginning.
+    kept: usize,
+    /// Number of removed items, they are placed right after the retained ones and followed by the unprocessed items.
+    removed: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> RetainGuard<'a, C> {
+    fn slot(&self, index: usize) -> *mut C::Item {
+        unsafe {
+            match index.checked_sub(self.left_len) {
+                None => self.left.add(index),
+                Some(index) => self.right.add(index),
+            }
+        }
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Drop for RetainGuard<'a, C> {
+    fn drop(&mut self) {
+        // Move retained items to the unprocessed ones (if any) to make all remaining items contiguous.
+        if self.removed > 0 {
+            for index in (0..self.kept).rev() {
+                let (src, dst) = (self.slot(index), self.slot(index + self.removed));
+                unsafe { ptr::copy_nonoverlapping(src, dst, 1) };
+            }
+        }
+        unsafe { self.inner.advance_read_index(self.removed) };
+    }
+}
+
 /// Iterator over ring buffer contents.
 ///
 /// *Please do not rely on actual type, it may change in future.*
@@ -388,11 +1010,13 @@ impl<D: DelegateConsumer> Consumer for D
 where
     D::Base: Consumer,
 {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_read_index(&self, value: usize) {
         self.base().set_read_index(value)
     }
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn advance_read_index(&self, count: usize) {
         self.base().advance_read_index(count)
     }
@@ -417,7 +1041,8 @@ where
         self.base_mut().as_mut_slices()
     }
 
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     fn try_pop(&mut self) -> Option<Self::Item> {
         self.base_mut().try_pop()
     }
@@ -430,6 +1055,11 @@ where
         self.base_mut().pop_slice(elems)
     }
 
+    #[inline]
+    fn pop_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        self.base_mut().pop_array()
+    }
+
     #[inline]
     fn iter(&self) -> Iter<'_, Self> {
         self.base().iter()
@@ -461,6 +1091,20 @@ macro_rules! impl_consumer_traits {
             }
         }
 
+        /// Serializes capacity and occupied items of the ring buffer.
+        #[cfg(feature = "serde")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? serde::Serialize for $type $(< $( $param ),+ >)?
+        where
+            <Self as $crate::traits::Observer>::Item: serde::Serialize,
+        {
+            fn serialize<Z: serde::Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+                $crate::serialize::serialize(self, serializer)
+            }
+        }
+
+        /// Returns `Err(WouldBlock)` if the ring buffer is empty, even if there is no producer anymore.
+        ///
+        /// Use [`Consumer::eof_reader`](crate::traits::Consumer::eof_reader) to get `Ok(0)` when the ring buffer is empty and the producer is closed.
         #[cfg(feature = "std")]
         impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? std::io::Read for $type $(< $( $param ),+ >)?
         where
@@ -1,5 +1,6 @@
 use super::{utils::modulus, Based};
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use crate::{rb::utils::ranges, utils::rem};
+use core::{fmt, mem::MaybeUninit, num::NonZeroUsize, ops::Range};
 
 /// Ring buffer observer.
 ///
@@ -12,6 +13,22 @@ pub trait Observer {
     /// It is constant during the whole ring buffer lifetime.
     fn capacity(&self) -> NonZeroUsize;
 
+    /// Whether the capacity of the ring buffer is a power of two.
+    ///
+    /// Index arithmetic of such ring buffers uses bit masks instead of division.
+    #[inline]
+    fn capacity_is_pow2(&self) -> bool {
+        self.capacity().is_power_of_two()
+    }
+
+    /// Mask that can be applied to an index to get a position in the ring buffer storage, equals to `capacity - 1`.
+    ///
+    /// Returns `None` if the capacity is not a power of two.
+    #[inline]
+    fn wrap_mask(&self) -> Option<usize> {
+        self.capacity_is_pow2().then(|| self.capacity().get() - 1)
+    }
+
     /// Index of the last item in the ring buffer.
     ///
     /// Index value is in range `0..(2 * capacity)`.
@@ -21,6 +38,21 @@ pub trait Observer {
     /// Index value is in range `0..(2 * capacity)`.
     fn write_index(&self) -> usize;
 
+    /// Pair of `(read, write)` indices.
+    ///
+    /// Indices are loaded one after another, not atomically, so the pair may mix states of the buffer
+    /// (e.g. `write` may already include items pushed after `read` was loaded).
+    /// Each index on its own is still valid for progress comparison.
+    ///
+    /// Can be used to detect progress without accessing items (e.g. by a watchdog):
+    /// if `read` differs from the previous call then the consumer has removed items,
+    /// if `write` differs then the producer has pushed items.
+    ///
+    /// *Indices are taken modulo `2 * capacity`, so exactly `2 * capacity` items passed between calls are indistinguishable from no progress.*
+    fn positions(&self) -> (usize, usize) {
+        (self.read_index(), self.write_index())
+    }
+
     /// Get slice between `start` and `end` indices.
     ///
     /// # Safety
@@ -35,8 +67,21 @@ pub trait Observer {
     /// # Safety
     ///
     /// There must not exist overlapping slices at the same time.
+    #[allow(clippy::mut_from_ref)]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]);
 
+    /// Pointer to the beginning of the ring buffer storage and its capacity.
+    ///
+    /// Intended for tooling and debugging only, e.g. to locate the ring buffer in a memory inspector.
+    ///
+    /// # Safety
+    ///
+    /// Returned pointer must not be used to access items.
+    unsafe fn storage_ptr_cap(&self) -> (*const MaybeUninit<Self::Item>, usize) {
+        let capacity = self.capacity().get();
+        (self.unsafe_slices(0, capacity).0.as_ptr(), capacity)
+    }
+
     /// Whether read end is held by consumer.
     fn read_is_held(&self) -> bool;
     /// Whether write end is held by producer.
@@ -47,7 +92,7 @@ pub trait Observer {
     /// *Actual number may be greater or less than returned value due to concurring activity of producer or consumer respectively.*
     fn occupied_len(&self) -> usize {
         let modulus = modulus(self);
-        (modulus.get() + self.write_index() - self.read_index()) % modulus
+        rem(modulus.get() + self.write_index() - self.read_index(), modulus)
     }
 
     /// The number of remaining free places in the buffer.
@@ -55,7 +100,77 @@ pub trait Observer {
     /// *Actual number may be greater or less than returned value due to concurring activity of consumer or producer respectively.*
     fn vacant_len(&self) -> usize {
         let modulus = modulus(self);
-        (self.capacity().get() + self.read_index() - self.write_index()) % modulus
+        rem(self.capacity().get() + self.read_index() - self.write_index(), modulus)
+    }
+
+    /// Positions of occupied items in the ring buffer storage.
+    ///
+    /// Returns a pair of index ranges, the second one may be empty.
+    /// They match slices returned by [`Consumer::occupied_slices`](`crate::traits::Consumer::occupied_slices`)
+    /// and may be used to describe the physical layout of the storage (e.g. for scatter/gather I/O).
+    fn occupied_ranges(&self) -> (Range<usize>, Range<usize>) {
+        ranges(self.capacity(), self.read_index(), self.write_index())
+    }
+
+    /// Snapshot of the ring buffer fill level.
+    ///
+    /// Doesn't allocate, so it is cheap enough for periodic metrics logging.
+    fn status(&self) -> BufferStatus {
+        let capacity = self.capacity().get();
+        let occupied = self.occupied_len();
+        BufferStatus {
+            occupied,
+            vacant: capacity - occupied,
+            capacity,
+        }
+    }
+
+    /// The number of items pushed to the ring buffer since the write index was equal to `saved_write_index`.
+    ///
+    /// `saved_write_index` must be a value previously returned by [`Self::write_index`].
+    ///
+    /// Indices are stored modulo `2 * capacity`, so if more than `capacity` items were pushed since then the exact number cannot be determined.
+    /// In that case the ring buffer is considered overrun and `capacity` is returned.
+    /// *The same applies if the producer has wrapped around so far that it reached `saved_write_index` again -
+    /// it is indistinguishable from no items being pushed.*
+    fn occupied_since(&self, saved_write_index: usize) -> usize {
+        let modulus = modulus(self);
+        let count = (modulus.get() + self.write_index() - saved_write_index % modulus) % modulus;
+        usize::min(count, self.capacity().get())
+    }
+
+    /// The number of items removed from the ring buffer before a reader with position `cursor` has seen them.
+    ///
+    /// `cursor` is an index of the next item the reader is going to read, e.g. a value previously returned by [`Self::write_index`].
+    /// Readers that don't hold the consumer (e.g. observers of an overwriting producer) may use it to detect data loss:
+    /// if the returned value is non-zero then the reader has lagged behind and these items are skipped,
+    /// and it should continue from [`Self::read_index`].
+    /// The number of items the reader is behind the write head is returned by [`Self::occupied_since`].
+    fn overrun_since(&self, cursor: usize) -> usize {
+        let modulus = modulus(self);
+        let read = self.read_index();
+        let cursor = cursor % modulus;
+        if (modulus.get() + cursor - read) % modulus <= self.occupied_len() {
+            0
+        } else {
+            usize::min((modulus.get() + read - cursor) % modulus, self.capacity().get())
+        }
+    }
+
+    /// Whether a reader with position `cursor` has lagged so far behind that some items it hasn't seen were removed
+    /// (e.g. overwritten by the producer).
+    ///
+    /// The number of items to be skipped on the next read is returned by [`Self::overrun_since`].
+    fn is_lagging(&self, cursor: usize) -> bool {
+        self.overrun_since(cursor) != 0
+    }
+
+    /// The number of items a reader with position `cursor` is behind the write head.
+    ///
+    /// Same as [`Self::occupied_since`], so it is never greater than `capacity`.
+    /// If the reader [is lagging](Self::is_lagging) then only the last `lag - overrun_since` items are still available to it.
+    fn lag(&self, cursor: usize) -> usize {
+        self.occupied_since(cursor)
     }
 
     /// Checks if the ring buffer is empty.
@@ -73,6 +188,56 @@ pub trait Observer {
     fn is_full(&self) -> bool {
         self.vacant_len() == 0
     }
+
+    /// Whether the ring buffer has been full at least once since it was created or [`Self::clear_overflow_flag`] was called.
+    ///
+    /// Useful to check whether the ring buffer capacity is sufficient.
+    ///
+    /// *Ring buffer implementations that don't track this flag always return `false`.*
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        false
+    }
+    /// Resets the flag returned by [`Self::has_been_full`].
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {}
+
+    /// The maximum number of items the ring buffer has ever contained since it was created or [`Self::reset_high_water_mark`] was called.
+    ///
+    /// The value is updated each time the producer advances the write index,
+    /// so it captures peaks even if the consumer has already removed items.
+    ///
+    /// *Ring buffer implementations that don't track high-water mark always return `0`.*
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        0
+    }
+    /// Resets the value returned by [`Self::high_water_mark`] to the current number of items.
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {}
+}
+
+/// Fill level of the ring buffer returned by [`Observer::status`].
+///
+/// Displayed as `occupied/capacity`, e.g. `7/16`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BufferStatus {
+    /// Number of items in the ring buffer.
+    pub occupied: usize,
+    /// Number of free places in the ring buffer.
+    pub vacant: usize,
+    /// Capacity of the ring buffer.
+    pub capacity: usize,
+}
+
+impl fmt::Display for BufferStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.occupied, self.capacity)
+    }
 }
 
 /// Trait used for delegating observer methods.
@@ -130,6 +295,11 @@ where
         self.base().vacant_len()
     }
 
+    #[inline]
+    fn occupied_ranges(&self) -> (Range<usize>, Range<usize>) {
+        self.base().occupied_ranges()
+    }
+
     #[inline]
     fn is_empty(&self) -> bool {
         self.base().is_empty()
@@ -139,4 +309,26 @@ where
     fn is_full(&self) -> bool {
         self.base().is_full()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.base().has_been_full()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.base().clear_overflow_flag()
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.base().high_water_mark()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.base().reset_high_water_mark()
+    }
 }
@@ -2,14 +2,18 @@ use super::{
     observer::{DelegateObserver, Observer},
     utils::modulus,
 };
+use crate::rb::utils::ranges;
 #[cfg(feature = "std")]
 use crate::utils::slice_assume_init_mut;
-use crate::utils::write_slice;
-use core::mem::MaybeUninit;
+use crate::utils::{add_mod, write_slice};
+use core::{
+    mem::MaybeUninit,
+    ops::{ControlFlow, Range},
+};
 #[cfg(feature = "std")]
 use std::{
     cmp,
-    io::{self, Read},
+    io::{self, IoSliceMut, Read},
 };
 
 /// Producer part of ring buffer.
@@ -30,8 +34,26 @@ pub trait Producer: Observer {
     /// First `count` items in free space must be initialized.
     ///
     /// Must not be called concurrently.
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn advance_write_index(&self, count: usize) {
-        self.set_write_index((self.write_index() + count) % modulus(self));
+        self.set_write_index(add_mod(self.write_index(), count, modulus(self)));
+    }
+
+    /// Same as [`Self::advance_write_index`] but checks that `count` doesn't exceed the vacant space.
+    ///
+    /// Returns `Err` containing current number of vacant places and doesn't move the pointer if it does.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::advance_write_index`].
+    unsafe fn advance_write_index_checked(&self, count: usize) -> Result<(), usize> {
+        let vacant = self.vacant_len();
+        if count > vacant {
+            return Err(vacant);
+        }
+        self.advance_write_index(count);
+        Ok(())
     }
 
     /// Provides a direct access to the ring buffer vacant memory.
@@ -41,6 +63,13 @@ pub trait Producer: Observer {
         unsafe { self.unsafe_slices(self.write_index(), self.read_index() + self.capacity().get()) }
     }
 
+    /// Positions of vacant slots in the ring buffer storage.
+    ///
+    /// Returns a pair of index ranges matching slices returned by [`Self::vacant_slices`], the second one may be empty.
+    fn vacant_ranges(&self) -> (Range<usize>, Range<usize>) {
+        ranges(self.capacity(), self.write_index(), self.read_index() + self.capacity().get())
+    }
+
     /// Mutable version of [`Self::vacant_slices`].
     ///
     /// Vacant memory is uninitialized. Initialized items must be put starting from the beginning of first slice.
@@ -54,9 +83,37 @@ pub trait Producer: Observer {
         unsafe { self.unsafe_slices_mut(self.write_index(), self.read_index() + self.capacity().get()) }
     }
 
+    /// Returns lengths of the slices returned by [`Self::vacant_slices`].
+    ///
+    /// Useful for planning how many items to write without accessing uninitialized memory.
+    fn vacant_lens(&self) -> (usize, usize) {
+        let (left, right) = self.vacant_slices();
+        (left.len(), right.len())
+    }
+
+    /// Returns a guard providing direct access to the ring buffer vacant memory.
+    ///
+    /// Items written to the vacant slices become visible to the consumer only after [`WriteGuard::commit`] call.
+    /// If the guard is dropped without committing then nothing is appended.
+    fn write_guard(&mut self) -> WriteGuard<'_, Self> {
+        WriteGuard::new(self)
+    }
+
+    /// Reserves exactly `count` vacant slots of the ring buffer.
+    ///
+    /// Returns `None` if there are less than `count` vacant slots. Unlike [`Self::write_guard`] the returned guard never provides fewer slots than requested.
+    ///
+    /// Items written to the reserved slots become visible to the consumer only after [`Reservation::commit`] call.
+    /// If the guard is dropped without committing then nothing is appended.
+    fn reserve(&mut self, count: usize) -> Option<Reservation<'_, Self>> {
+        Reservation::new(self, count)
+    }
+
     /// Appends an item to the ring buffer.
     ///
     /// If buffer is full returns an `Err` containing the item that hasn't been appended.
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
         if !self.is_full() {
             unsafe {
@@ -69,6 +126,20 @@ pub trait Producer: Observer {
         }
     }
 
+    /// Appends an item to the ring buffer reporting how much space is left after that.
+    ///
+    /// Unlike [`Self::try_push`] this method tells when the item has taken the last vacant slot,
+    /// so the caller can back off proactively instead of discovering a full buffer on the next call.
+    fn push_status(&mut self, elem: Self::Item) -> PushStatus<Self::Item> {
+        match self.try_push(elem) {
+            Ok(()) => match self.vacant_len() {
+                0 => PushStatus::AcceptedLast,
+                remaining_vacant => PushStatus::Accepted { remaining_vacant },
+            },
+            Err(elem) => PushStatus::Rejected(elem),
+        }
+    }
+
     /// Appends items from an iterator to the ring buffer.
     /// Elements that haven't been added to the ring buffer remain in the iterator.
     ///
@@ -90,6 +161,37 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Appends items from an iterator to the ring buffer while they pass `validate`.
+    ///
+    /// Returns:
+    /// + `Ok(count)` - the ring buffer is full or the iterator has ended,
+    /// + `Err((count, item))` - `item` has failed validation, it is removed from the iterator but not appended.
+    ///
+    /// `count` is the number of items been appended to the ring buffer.
+    /// Elements that haven't been taken from the iterator remain in it.
+    fn push_iter_validated<I: Iterator<Item = Self::Item>, F: Fn(&Self::Item) -> bool>(
+        &mut self,
+        iter: &mut I,
+        validate: F,
+    ) -> Result<usize, (usize, Self::Item)> {
+        let (left, right) = self.vacant_slices_mut();
+        let mut count = 0;
+        let mut result = Ok(());
+        for place in left.iter_mut().chain(right.iter_mut()) {
+            match iter.next() {
+                Some(elem) if validate(&elem) => unsafe { place.as_mut_ptr().write(elem) },
+                Some(elem) => {
+                    result = Err(elem);
+                    break;
+                }
+                None => break,
+            }
+            count += 1;
+        }
+        unsafe { self.advance_write_index(count) };
+        result.map(|()| count).map_err(|elem| (count, elem))
+    }
+
     /// Appends items from slice to the ring buffer.
     ///
     /// Returns count of items been appended to the ring buffer.
@@ -117,6 +219,96 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Appends all items from slice to the ring buffer calling `on_full` each time the ring buffer is full.
+    ///
+    /// `on_full` is supposed to make room in the ring buffer (e.g. by waiting or by driving the consumer).
+    /// Pushing continues while it returns [`ControlFlow::Continue`].
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn push_slice_with<F: FnMut() -> ControlFlow<()>>(&mut self, elems: &[Self::Item], mut on_full: F) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let mut count = 0;
+        loop {
+            count += self.push_slice(&elems[count..]);
+            if count == elems.len() || on_full().is_break() {
+                break count;
+            }
+        }
+    }
+
+    /// Appends the whole slice to the ring buffer only if there is enough vacant space for all its items.
+    ///
+    /// Returns `false` and appends nothing otherwise, so a logical record is never split across a full buffer boundary.
+    fn push_slice_all_or_none(&mut self, elems: &[Self::Item]) -> bool
+    where
+        Self::Item: Copy,
+    {
+        if self.vacant_len() < elems.len() {
+            return false;
+        }
+        let count = self.push_slice(elems);
+        debug_assert_eq!(count, elems.len());
+        true
+    }
+
+    /// Appends all items of the array to the ring buffer at once.
+    ///
+    /// If there is not enough vacant space for all `N` items then nothing is appended and the array is returned back in `Err`.
+    fn push_array<const N: usize>(&mut self, elems: [Self::Item; N]) -> Result<(), [Self::Item; N]> {
+        if self.vacant_len() < N {
+            return Err(elems);
+        }
+        let (left, right) = self.vacant_slices_mut();
+        for (place, elem) in left.iter_mut().chain(right.iter_mut()).zip(elems) {
+            place.write(elem);
+        }
+        unsafe { self.advance_write_index(N) };
+        Ok(())
+    }
+
+    /// Appends `u16` to the ring buffer as 2 bytes in native byte order.
+    ///
+    /// If there is not enough vacant space for all bytes then nothing is appended and the value is returned back in `Err`.
+    fn push_u16_ne(&mut self, value: u16) -> Result<(), u16>
+    where
+        Self: Producer<Item = u8>,
+    {
+        self.push_array(value.to_ne_bytes()).map_err(u16::from_ne_bytes)
+    }
+
+    /// Appends `u32` to the ring buffer as 4 bytes in native byte order.
+    ///
+    /// If there is not enough vacant space for all bytes then nothing is appended and the value is returned back in `Err`.
+    fn push_u32_ne(&mut self, value: u32) -> Result<(), u32>
+    where
+        Self: Producer<Item = u8>,
+    {
+        self.push_array(value.to_ne_bytes()).map_err(u32::from_ne_bytes)
+    }
+
+    /// Appends `u64` to the ring buffer as 8 bytes in native byte order.
+    ///
+    /// If there is not enough vacant space for all bytes then nothing is appended and the value is returned back in `Err`.
+    fn push_u64_ne(&mut self, value: u64) -> Result<(), u64>
+    where
+        Self: Producer<Item = u8>,
+    {
+        self.push_array(value.to_ne_bytes()).map_err(u64::from_ne_bytes)
+    }
+
+    /// Moves items from the beginning of `vec` to the ring buffer.
+    ///
+    /// Items that haven't been pushed remain in `vec`.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    #[cfg(feature = "arrayvec")]
+    fn push_from_arrayvec<const N: usize>(&mut self, vec: &mut arrayvec::ArrayVec<Self::Item, N>) -> usize {
+        let count = usize::min(vec.len(), self.vacant_len());
+        self.push_iter(vec.drain(..count))
+    }
+
     #[cfg(feature = "std")]
     /// Reads at most `count` bytes from `Read` instance and appends them to the ring buffer.
     /// If `count` is `None` then as much as possible bytes will be read.
@@ -150,6 +342,150 @@ pub trait Producer: Observer {
         unsafe { self.advance_write_index(read_count) };
         Some(Ok(read_count))
     }
+
+    #[cfg(feature = "std")]
+    /// Reads bytes from `Read` instance into both vacant slices of the ring buffer at once using [`Read::read_vectored`].
+    ///
+    /// Unlike [`Self::read_from`] this may fill the whole vacant space with a single call even if it wraps around the end of the storage.
+    ///
+    /// Returns number of bytes been read. If the ring buffer is full then `read_vectored` isn't called at all and `Ok(0)` is returned.
+    /// In case of error no items are appended to the ring buffer.
+    fn read_vectored_from<S: Read>(&mut self, reader: &mut S) -> io::Result<usize>
+    where
+        Self: Producer<Item = u8>,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        let count = left.len() + right.len();
+        if count == 0 {
+            return Ok(0);
+        }
+
+        // Initialize memory before read (see `read_from`).
+        left.fill(MaybeUninit::new(0));
+        right.fill(MaybeUninit::new(0));
+        let mut bufs = unsafe {
+            [
+                IoSliceMut::new(slice_assume_init_mut(left)),
+                IoSliceMut::new(slice_assume_init_mut(right)),
+            ]
+        };
+
+        let read_count = reader.read_vectored(&mut bufs)?;
+        assert!(read_count <= count);
+        unsafe { self.advance_write_index(read_count) };
+        Ok(read_count)
+    }
+}
+
+/// Result of [`Producer::push_status`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PushStatus<T> {
+    /// Item has been pushed and there are still `remaining_vacant` free places in the buffer.
+    Accepted { remaining_vacant: usize },
+    /// Item has been pushed and the buffer is full now.
+    AcceptedLast,
+    /// Buffer is full, item is returned back.
+    Rejected(T),
+}
+
+/// Guard providing access to the vacant memory of the ring buffer.
+///
+/// Created by [`Producer::write_guard`].
+pub struct WriteGuard<'a, P: Producer + ?Sized> {
+    inner: &'a P,
+    left: &'a mut [MaybeUninit<P::Item>],
+    right: &'a mut [MaybeUninit<P::Item>],
+}
+
+impl<'a, P: Producer + ?Sized> WriteGuard<'a, P> {
+    /// Create a guard.
+    pub fn new(inner: &'a mut P) -> Self {
+        let end = inner.read_index() + inner.capacity().get();
+        Self::with_end(inner, end)
+    }
+
+    /// Create a guard over vacant slots from the write index up to `end`.
+    fn with_end(inner: &'a mut P, end: usize) -> Self {
+        let inner: &'a P = inner;
+        let (left, right) = unsafe { inner.unsafe_slices_mut(inner.write_index(), end) };
+        Self { inner, left, right }
+    }
+
+    /// Pair of vacant slices, the second one may be empty.
+    ///
+    /// Items must be put starting from the beginning of the first slice and continue in the second one.
+    pub fn vacant_slices_mut(&mut self) -> (&mut [MaybeUninit<P::Item>], &mut [MaybeUninit<P::Item>]) {
+        (&mut *self.left, &mut *self.right)
+    }
+
+    /// Total number of vacant slots.
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len()
+    }
+    /// Whether there are no vacant slots.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append first `count` items of the vacant slices to the ring buffer.
+    ///
+    /// *Panics if `count` is greater than [`Self::len`].*
+    ///
+    /// # Safety
+    ///
+    /// First `count` items in the vacant slices must be initialized.
+    pub unsafe fn commit(self, count: usize) {
+        assert!(count <= self.len());
+        self.inner.advance_write_index(count);
+    }
+}
+
+/// Guard providing access to the exact number of reserved vacant slots of the ring buffer.
+///
+/// Created by [`Producer::reserve`].
+pub struct Reservation<'a, P: Producer + ?Sized> {
+    guard: WriteGuard<'a, P>,
+}
+
+impl<'a, P: Producer + ?Sized> Reservation<'a, P> {
+    /// Reserve `count` slots.
+    ///
+    /// Returns `None` if there are less than `count` vacant slots.
+    pub fn new(inner: &'a mut P, count: usize) -> Option<Self> {
+        if inner.vacant_len() < count {
+            return None;
+        }
+        let end = add_mod(inner.write_index(), count, modulus(inner));
+        Some(Self {
+            guard: WriteGuard::with_end(inner, end),
+        })
+    }
+
+    /// Pair of reserved slices, the second one may be empty.
+    ///
+    /// Items must be put starting from the beginning of the first slice and continue in the second one.
+    pub fn vacant_slices_mut(&mut self) -> (&mut [MaybeUninit<P::Item>], &mut [MaybeUninit<P::Item>]) {
+        self.guard.vacant_slices_mut()
+    }
+
+    /// Number of reserved slots.
+    pub fn len(&self) -> usize {
+        self.guard.len()
+    }
+    /// Whether nothing is reserved.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append all reserved items to the ring buffer.
+    ///
+    /// # Safety
+    ///
+    /// All items in the reserved slices must be initialized.
+    pub unsafe fn commit(self) {
+        let count = self.len();
+        self.guard.commit(count);
+    }
 }
 
 /// Trait used for delegating consumer methods.
@@ -163,11 +499,13 @@ impl<D: DelegateProducer> Producer for D
 where
     D::Base: Producer,
 {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_write_index(&self, value: usize) {
         self.base().set_write_index(value)
     }
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn advance_write_index(&self, count: usize) {
         self.base().advance_write_index(count)
     }
@@ -177,12 +515,18 @@ where
         self.base().vacant_slices()
     }
 
+    #[inline]
+    fn vacant_ranges(&self) -> (Range<usize>, Range<usize>) {
+        self.base().vacant_ranges()
+    }
+
     #[inline]
     fn vacant_slices_mut(&mut self) -> (&mut [core::mem::MaybeUninit<Self::Item>], &mut [core::mem::MaybeUninit<Self::Item>]) {
         self.base_mut().vacant_slices_mut()
     }
 
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
         self.base_mut().try_push(elem)
     }
@@ -199,10 +543,18 @@ where
     {
         self.base_mut().push_slice(elems)
     }
+
+    #[inline]
+    fn push_array<const N: usize>(&mut self, elems: [Self::Item; N]) -> Result<(), [Self::Item; N]> {
+        self.base_mut().push_array(elems)
+    }
 }
 
 macro_rules! impl_producer_traits {
     ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
+        $crate::traits::producer::impl_producer_traits!($type $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)?, |_this| ());
+    };
+    ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?, |$this:ident| $flush:expr) => {
 
         #[cfg(feature = "std")]
         impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? std::io::Write for $type $(< $( $param ),+ >)?
@@ -218,6 +570,8 @@ macro_rules! impl_producer_traits {
                 }
             }
             fn flush(&mut self) -> std::io::Result<()> {
+                let $this = self;
+                $flush;
                 Ok(())
             }
          }
@@ -235,6 +589,21 @@ macro_rules! impl_producer_traits {
                 }
             }
          }
+
+        /// Appends items from an iterator until the ring buffer is full.
+        ///
+        /// Items that don't fit into the ring buffer are not taken from the iterator and dropped along with it.
+        /// Use [`Producer::push_iter`] to keep them or to get the number of appended items.
+        ///
+        /// To extend from an iterator of references to `Copy` items use [`Iterator::copied`].
+        impl<T, $( $( $param $( : $first_bound $(+ $next_bound )* )? ),+ )?> core::iter::Extend<T> for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Producer<Item = T>,
+        {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                self.push_iter(iter.into_iter());
+            }
+         }
     };
  }
 pub(crate) use impl_producer_traits;
@@ -3,6 +3,7 @@ use super::{
     producer::{DelegateProducer, Producer},
     Observer,
 };
+use core::{mem::MaybeUninit, ptr};
 
 /// An abstract ring buffer that exclusively owns its data.
 pub trait RingBuffer: Observer + Consumer + Producer {
@@ -26,6 +27,9 @@ pub trait RingBuffer: Observer + Consumer + Producer {
     /// Pushes an item to the ring buffer overwriting the latest item if the buffer is full.
     ///
     /// Returns overwritten item if overwriting took place.
+    ///
+    /// *Overwriting methods are provided only for the whole ring buffer but not for [`Producer`],*
+    /// *because they also move the read index and therefore require exclusive access to both ends.*
     fn push_overwrite(&mut self, elem: Self::Item) -> Option<Self::Item> {
         let ret = if self.is_full() { self.try_pop() } else { None };
         let _ = self.try_push(elem);
@@ -58,6 +62,85 @@ pub trait RingBuffer: Observer + Consumer + Producer {
             elems
         });
     }
+
+    /// Rotates the order of stored items `n` places to the left, so that the item at position `n` becomes the first.
+    ///
+    /// `n` may be greater than number of items, it is taken modulo [`Observer::occupied_len`].
+    ///
+    /// Items are moved one by one from the beginning to the end of the ring buffer.
+    fn rotate_left(&mut self, n: usize) {
+        let len = self.occupied_len();
+        if len == 0 {
+            return;
+        }
+        for _ in 0..(n % len) {
+            let elem = self.try_pop().unwrap();
+            assert!(self.try_push(elem).is_ok());
+        }
+    }
+
+    /// Rotates the order of stored items `n` places to the right, so that the last `n` items become the first.
+    ///
+    /// `n` may be greater than number of items, it is taken modulo [`Observer::occupied_len`].
+    fn rotate_right(&mut self, n: usize) {
+        let len = self.occupied_len();
+        if len == 0 {
+            return;
+        }
+        self.rotate_left(len - n % len);
+    }
+
+    /// Removes all items from the ring buffer and then fills the whole ring buffer with copies of `value`.
+    ///
+    /// After this call the ring buffer is full.
+    fn fill_with(&mut self, value: Self::Item)
+    where
+        Self::Item: Copy,
+    {
+        self.clear();
+        let (left, right) = self.vacant_slices_mut();
+        left.fill(MaybeUninit::new(value));
+        right.fill(MaybeUninit::new(value));
+        unsafe { self.advance_write_index(self.capacity().get()) };
+    }
+
+    /// Removes all items from the ring buffer and then fills the whole ring buffer with `byte`.
+    ///
+    /// Same as [`Self::fill_with`] but sets memory directly without copying items one by one.
+    fn fill_bytes(&mut self, byte: u8)
+    where
+        Self::Item: Byte,
+    {
+        self.clear();
+        let (left, right) = self.vacant_slices_mut();
+        unsafe {
+            ptr::write_bytes(left.as_mut_ptr() as *mut u8, byte, left.len());
+            ptr::write_bytes(right.as_mut_ptr() as *mut u8, byte, right.len());
+            self.advance_write_index(self.capacity().get());
+        }
+    }
+
+    /// Removes and drops all items from the ring buffer and then appends items from `items` instead.
+    ///
+    /// Only first `capacity` items are taken from the iterator.
+    /// New items are committed all at once, so the previous contents are replaced in a single logical operation.
+    ///
+    /// Returns count of items been appended.
+    fn replace_all<I: IntoIterator<Item = Self::Item>>(&mut self, items: I) -> usize {
+        self.clear();
+        self.push_iter(items.into_iter())
+    }
+}
+
+/// Item type that is a single byte, so that memory of items can be set directly.
+///
+/// Implemented only for [`u8`].
+pub trait Byte: Copy + sealed::Sealed {}
+impl Byte for u8 {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u8 {}
 }
 
 /// Trait used for delegating owning ring buffer methods.
@@ -95,4 +178,20 @@ where
     {
         self.base_mut().push_slice_overwrite(elems)
     }
+
+    #[inline]
+    fn fill_with(&mut self, value: Self::Item)
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().fill_with(value)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, byte: u8)
+    where
+        Self::Item: Byte,
+    {
+        self.base_mut().fill_bytes(byte)
+    }
 }
@@ -7,22 +7,55 @@ use crate::{consumer::Consumer, producer::Producer};
 ///
 /// Returns number of items been moved.
 pub fn transfer<T, C: Consumer<Item = T>, P: Producer<Item = T>>(src: &mut C, dst: &mut P, count: Option<usize>) -> usize {
-    let (src_left, src_right) = src.occupied_slices();
-    let (dst_left, dst_right) = dst.vacant_slices_mut();
-    let src_iter = src_left.iter().chain(src_right.iter());
-    let dst_iter = dst_left.iter_mut().chain(dst_right.iter_mut());
-
-    let mut actual_count = 0;
-    for (src_elem, dst_place) in src_iter.zip(dst_iter) {
-        if let Some(count) = count {
-            if actual_count >= count {
-                break;
-            }
+    let (mut dst_left, mut dst_right) = dst.vacant_slices_mut();
+    if let Some(count) = count {
+        if count < dst_left.len() {
+            dst_left = &mut dst_left[..count];
+            dst_right = &mut [];
+        } else if count < dst_left.len() + dst_right.len() {
+            dst_right = &mut dst_right[..(count - dst_left.len())];
         }
-        unsafe { dst_place.write(src_elem.as_ptr().read()) };
-        actual_count += 1;
     }
-    unsafe { src.advance_read_index(actual_count) };
+    let actual_count = src.pop_into_slices(dst_left, dst_right);
     unsafe { dst.advance_write_index(actual_count) };
     actual_count
 }
+
+/// Moves as much items as possible from the `src` consumer to the `dst` producer.
+///
+/// Items are moved until `src` is empty or `dst` is full (at the moment of the call)
+/// even if occupied memory of `src` or vacant memory of `dst` wraps around the end of the storage.
+///
+/// Returns number of items been moved.
+pub fn transfer_all<T, C: Consumer<Item = T>, P: Producer<Item = T>>(src: &mut C, dst: &mut P) -> usize {
+    transfer(src, dst, None)
+}
+
+/// Moves exactly `count` items from the `src` consumer to the `dst` producer or nothing at all.
+///
+/// Items are moved only if `src` contains at least `count` items and `dst` has at least `count` vacant places,
+/// otherwise both are left untouched and `false` is returned.
+pub fn transfer_exact<T, C: Consumer<Item = T>, P: Producer<Item = T>>(src: &mut C, dst: &mut P, count: usize) -> bool {
+    if src.occupied_len() < count || dst.vacant_len() < count {
+        return false;
+    }
+    let actual_count = transfer(src, dst, Some(count));
+    debug_assert_eq!(actual_count, count);
+    true
+}
+
+/// Moves at most `count` items from the `src` consumer to the `dst` producer converting each item with `f`.
+///
+/// Stops when `src` is empty or `dst` is full.
+/// `count` is the number of items being moved, if `None` - as much as possible items will be moved.
+///
+/// Returns number of items been moved.
+pub fn transfer_map<T, U, C: Consumer<Item = T>, P: Producer<Item = U>, F: FnMut(T) -> U>(
+    src: &mut C,
+    dst: &mut P,
+    count: Option<usize>,
+    f: F,
+) -> usize {
+    let count = usize::min(count.unwrap_or(usize::MAX), dst.vacant_len());
+    dst.push_iter(src.pop_iter().take(count).map(f))
+}
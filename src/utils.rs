@@ -1,10 +1,56 @@
+use crate::traits::Observer;
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    fmt,
     mem::{self, MaybeUninit},
+    num::NonZeroUsize,
     ptr,
 };
 
+/// Remainder of `value` divided by `divisor`.
+///
+/// Uses bit mask instead of division if `divisor` is a power of two.
+///
+/// The branch is folded at compile time for array-backed ring buffers and is always predicted for heap ones,
+/// so it costs nothing compared to plain division when `divisor` is not a power of two.
+#[inline]
+pub fn rem(value: usize, divisor: NonZeroUsize) -> usize {
+    if divisor.is_power_of_two() {
+        value & (divisor.get() - 1)
+    } else {
+        value % divisor
+    }
+}
+
+/// Sum of `index` and `count` modulo `modulus`.
+///
+/// `index` must be less than `modulus` and `count` must not be greater than `modulus`.
+/// Wraps by subtraction instead of division.
+///
+/// `index + count` doesn't overflow because `modulus` is not greater than `2 * MAX_CAPACITY`, that is less than `usize::MAX / 2`.
+#[inline]
+pub fn add_mod(index: usize, count: usize, modulus: NonZeroUsize) -> usize {
+    let sum = index + count;
+    if sum >= modulus.get() {
+        sum - modulus.get()
+    } else {
+        sum
+    }
+}
+
+/// Quotient and remainder of `value` divided by `divisor`.
+///
+/// Uses bit operations instead of division if `divisor` is a power of two.
+#[inline]
+pub fn div_rem(value: usize, divisor: NonZeroUsize) -> (usize, usize) {
+    if divisor.is_power_of_two() {
+        (value >> divisor.trailing_zeros(), value & (divisor.get() - 1))
+    } else {
+        (value / divisor, value % divisor)
+    }
+}
+
 // TODO: Remove on `maybe_uninit_uninit_array` stabilization.
 pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
     unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
@@ -38,6 +84,12 @@ pub fn move_uninit_slice<T>(dst: &mut [MaybeUninit<T>], src: &[MaybeUninit<T>])
     }
 }
 
+// TODO: Remove on `maybe_uninit_array_assume_init` stabilization.
+pub unsafe fn array_assume_init<T, const N: usize>(value: [MaybeUninit<T>; N]) -> [T; N] {
+    let ptr = &value as *const _ as *const [T; N];
+    ptr.read()
+}
+
 pub fn array_to_uninit<T, const N: usize>(value: [T; N]) -> [MaybeUninit<T>; N] {
     let value = mem::ManuallyDrop::new(value);
     let ptr = &value as *const _ as *const [MaybeUninit<T>; N];
@@ -57,3 +109,23 @@ pub fn boxed_slice_to_uninit<T>(value: Box<[T]>) -> Box<[MaybeUninit<T>]> {
     let ptr = &value as *const _ as *const Box<[MaybeUninit<T>]>;
     unsafe { ptr.read() }
 }
+
+/// Whether the opposite side of the ring buffer is released for a wrapper holding `prod` and `cons` ends.
+///
+/// If the wrapper holds both or none of the ends then both of them must be released.
+pub fn is_abandoned<O: Observer + ?Sized>(rb: &O, prod: bool, cons: bool) -> bool {
+    match (prod, cons) {
+        (true, false) => !rb.read_is_held(),
+        (false, true) => !rb.write_is_held(),
+        _ => !rb.read_is_held() && !rb.write_is_held(),
+    }
+}
+
+/// Formats ring buffer state without accessing its items.
+pub fn debug_observer<O: Observer + ?Sized>(this: &O, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct(name)
+        .field("capacity", &this.capacity())
+        .field("occupied_len", &this.occupied_len())
+        .field("vacant_len", &this.vacant_len())
+        .finish()
+}
@@ -10,12 +10,17 @@ use crate::{
         producer::{impl_producer_traits, Producer},
         Observer,
     },
+    utils::debug_observer,
 };
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use core::{fmt, mem::MaybeUninit, num::NonZeroUsize};
 
 /// Caching wrapper of a ring buffer.
+///
+/// By default changes are committed to the ring buffer after each operation.
+/// This can be turned off with [`Self::set_auto_sync`].
 pub struct Caching<R: RbRef, const P: bool, const C: bool> {
     frozen: Frozen<R, P, C>,
+    auto_sync: bool,
 }
 
 /// Caching producer implementation.
@@ -28,7 +33,10 @@ impl<R: RbRef, const P: bool, const C: bool> Caching<R, P, C> {
     ///
     /// Panics if wrapper with matching rights already exists.
     pub fn new(rb: R) -> Self {
-        Self { frozen: Frozen::new(rb) }
+        Self {
+            frozen: Frozen::new(rb),
+            auto_sync: true,
+        }
     }
 
     /// Get ring buffer observer.
@@ -40,6 +48,38 @@ impl<R: RbRef, const P: bool, const C: bool> Caching<R, P, C> {
     pub fn freeze(self) -> Frozen<R, P, C> {
         self.frozen
     }
+
+    /// Whether the opposite side of the ring buffer has been dropped (or closed).
+    ///
+    /// See [`Frozen::is_abandoned`].
+    pub fn is_abandoned(&self) -> bool {
+        self.frozen.is_abandoned()
+    }
+
+    /// Commit changes to and fetch updates from the ring buffer.
+    pub fn sync(&self) {
+        self.frozen.sync()
+    }
+
+    /// Whether changes are committed to the ring buffer after each operation.
+    pub fn auto_sync(&self) -> bool {
+        self.auto_sync
+    }
+    /// Enable or disable committing changes to the ring buffer after each operation.
+    ///
+    /// When disabled, pushed items (or space of removed items) aren't visible to the opposite side
+    /// until [`Self::sync`] is called or `self` is dropped.
+    /// This reduces the number of stores to shared indices for bursty workloads at the cost of increased latency,
+    /// and makes the whole batch of changes visible at once.
+    /// Changes made by the opposite side are still fetched when there is not enough items or space.
+    ///
+    /// Enabling auto sync commits pending changes immediately.
+    pub fn set_auto_sync(&mut self, enabled: bool) {
+        self.auto_sync = enabled;
+        if enabled {
+            self.frozen.commit();
+        }
+    }
 }
 
 impl<R: RbRef, const P: bool, const C: bool> Wrap for Caching<R, P, C> {
@@ -64,6 +104,18 @@ impl<R: RbRef, const P: bool, const C: bool> AsMut<Self> for Caching<R, P, C> {
     }
 }
 
+/// Shows the ring buffer state but not its items.
+impl<R: RbRef, const P: bool, const C: bool> fmt::Debug for Caching<R, P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match (P, C) {
+            (true, false) => "CachingProd",
+            (false, true) => "CachingCons",
+            _ => "Caching",
+        };
+        debug_observer(self, name, f)
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Observer for Caching<R, P, C> {
     type Item = <R::Rb as Observer>::Item;
 
@@ -102,21 +154,48 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Caching<R, P, C> {
     fn write_is_held(&self) -> bool {
         self.frozen.write_is_held()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.frozen.has_been_full()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.frozen.clear_overflow_flag()
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.frozen.high_water_mark()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.frozen.reset_high_water_mark()
+    }
 }
 
 impl<R: RbRef> Producer for CachingProd<R> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_write_index(&self, value: usize) {
         self.frozen.set_write_index(value);
-        self.frozen.commit();
+        if self.auto_sync {
+            self.frozen.commit();
+        }
     }
 
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
         if self.frozen.is_full() {
             self.frozen.fetch();
         }
         let r = self.frozen.try_push(elem);
-        if r.is_ok() {
+        if r.is_ok() && self.auto_sync {
             self.frozen.commit();
         }
         r
@@ -124,23 +203,28 @@ impl<R: RbRef> Producer for CachingProd<R> {
 }
 
 impl<R: RbRef> Consumer for CachingCons<R> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_read_index(&self, value: usize) {
         self.frozen.set_read_index(value);
-        self.frozen.commit();
+        if self.auto_sync {
+            self.frozen.commit();
+        }
     }
 
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     fn try_pop(&mut self) -> Option<<Self as Observer>::Item> {
         if self.frozen.is_empty() {
             self.frozen.fetch();
         }
         let r = self.frozen.try_pop();
-        if r.is_some() {
+        if r.is_some() && self.auto_sync {
             self.frozen.commit();
         }
         r
     }
 }
 
-impl_producer_traits!(CachingProd<R: RbRef>);
+impl_producer_traits!(CachingProd<R: RbRef>, |this| this.sync());
 impl_consumer_traits!(CachingCons<R: RbRef>);
@@ -10,8 +10,11 @@ use crate::{
         producer::{impl_producer_traits, Producer},
         Observer, RingBuffer,
     },
+    utils::{debug_observer, is_abandoned},
 };
 use core::{
+    fmt,
+    hash::{Hash, Hasher},
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
     ptr,
@@ -29,6 +32,9 @@ pub type Prod<R> = Direct<R, true, false>;
 /// Consumer of a ring buffer.
 pub type Cons<R> = Direct<R, false, true>;
 
+/// Cloning an observer only clones the ring buffer reference.
+///
+/// Observer doesn't hold the producer or the consumer, so dropping it never affects their state.
 impl<R: RbRef> Clone for Obs<R> {
     fn clone(&self) -> Self {
         Self { rb: self.rb.clone() }
@@ -60,6 +66,16 @@ impl<R: RbRef, const P: bool, const C: bool> Direct<R, P, C> {
         unsafe { Frozen::new_unchecked(ptr::read(&this.rb)) }
     }
 
+    /// Whether the opposite side of the ring buffer has been dropped (or closed).
+    ///
+    /// For producer it means that there is no consumer and vice versa.
+    /// For observer it means that there is neither producer nor consumer.
+    ///
+    /// Observers don't hold the ring buffer, so their number doesn't affect the result.
+    pub fn is_abandoned(&self) -> bool {
+        is_abandoned(self.rb(), P, C)
+    }
+
     /// # Safety
     ///
     /// Must not be used after this call.
@@ -98,6 +114,36 @@ impl<R: RbRef, const P: bool, const C: bool> AsMut<Self> for Direct<R, P, C> {
     }
 }
 
+/// Wrappers are equal if they refer to the same ring buffer.
+///
+/// Equality is by buffer identity, not by buffer contents.
+impl<R: RbRef, const P: bool, const C: bool> PartialEq for Direct<R, P, C> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.rb() as *const R::Rb as *const (), other.rb() as *const R::Rb as *const ())
+    }
+}
+impl<R: RbRef, const P: bool, const C: bool> Eq for Direct<R, P, C> {}
+
+/// Hash of the ring buffer address, consistent with [`PartialEq`] implementation.
+impl<R: RbRef, const P: bool, const C: bool> Hash for Direct<R, P, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.rb() as *const R::Rb as *const ()).hash(state);
+    }
+}
+
+/// Shows the ring buffer state but not its items.
+impl<R: RbRef, const P: bool, const C: bool> fmt::Debug for Direct<R, P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match (P, C) {
+            (false, false) => "Obs",
+            (true, false) => "Prod",
+            (false, true) => "Cons",
+            (true, true) => "Direct",
+        };
+        debug_observer(self, name, f)
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Observer for Direct<R, P, C> {
     type Item = <R::Rb as Observer>::Item;
 
@@ -129,17 +175,41 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Direct<R, P, C> {
     fn write_is_held(&self) -> bool {
         self.rb().write_is_held()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.rb().has_been_full()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.rb().clear_overflow_flag()
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.rb().high_water_mark()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.rb().reset_high_water_mark()
+    }
 }
 
 impl<R: RbRef> Producer for Prod<R> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_write_index(&self, value: usize) {
         self.rb().set_write_index(value)
     }
 }
 
 impl<R: RbRef> Consumer for Cons<R> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_read_index(&self, value: usize) {
         self.rb().set_read_index(value)
     }
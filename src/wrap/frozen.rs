@@ -10,9 +10,11 @@ use crate::{
         producer::{impl_producer_traits, Producer},
         Observer, RingBuffer,
     },
+    utils::{debug_observer, is_abandoned},
 };
 use core::{
     cell::Cell,
+    fmt,
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
     ptr,
@@ -69,6 +71,13 @@ impl<R: RbRef, const P: bool, const C: bool> Frozen<R, P, C> {
         Obs::new(self.rb.clone())
     }
 
+    /// Whether the opposite side of the ring buffer has been dropped (or closed).
+    ///
+    /// See [`Direct::is_abandoned`](super::direct::Direct::is_abandoned).
+    pub fn is_abandoned(&self) -> bool {
+        is_abandoned(self.rb(), P, C)
+    }
+
     unsafe fn close(&mut self) {
         if P {
             self.rb().hold_write(false);
@@ -148,6 +157,18 @@ impl<R: RbRef> FrozenProd<R> {
     }
 }
 
+/// Shows the ring buffer state but not its items.
+impl<R: RbRef, const P: bool, const C: bool> fmt::Debug for Frozen<R, P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match (P, C) {
+            (true, false) => "FrozenProd",
+            (false, true) => "FrozenCons",
+            _ => "Frozen",
+        };
+        debug_observer(self, name, f)
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Observer for Frozen<R, P, C> {
     type Item = <R::Rb as Observer>::Item;
 
@@ -180,17 +201,41 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Frozen<R, P, C> {
     fn write_is_held(&self) -> bool {
         self.rb().write_is_held()
     }
+
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn has_been_full(&self) -> bool {
+        self.rb().has_been_full()
+    }
+    #[cfg(feature = "overflow-flag")]
+    #[inline]
+    fn clear_overflow_flag(&self) {
+        self.rb().clear_overflow_flag()
+    }
+
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn high_water_mark(&self) -> usize {
+        self.rb().high_water_mark()
+    }
+    #[cfg(feature = "high-water-mark")]
+    #[inline]
+    fn reset_high_water_mark(&self) {
+        self.rb().reset_high_water_mark()
+    }
 }
 
 impl<R: RbRef> Producer for FrozenProd<R> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_write_index(&self, value: usize) {
         self.write.set(value);
     }
 }
 
 impl<R: RbRef> Consumer for FrozenCons<R> {
-    #[inline]
+    #[cfg_attr(feature = "force-inline", inline(always))]
+    #[cfg_attr(not(feature = "force-inline"), inline)]
     unsafe fn set_read_index(&self, value: usize) {
         self.read.set(value);
     }
@@ -203,5 +248,5 @@ impl<R: RbRef, const P: bool, const C: bool> Drop for Frozen<R, P, C> {
     }
 }
 
-impl_producer_traits!(FrozenProd<R: RbRef>);
+impl_producer_traits!(FrozenProd<R: RbRef>, |this| this.sync());
 impl_consumer_traits!(FrozenCons<R: RbRef>);
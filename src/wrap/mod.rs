@@ -1,9 +1,11 @@
 pub mod caching;
 pub mod direct;
 pub mod frozen;
+pub mod page;
 mod traits;
 
 pub use caching::{CachingCons, CachingProd};
 pub use direct::{Cons, Obs, Prod};
 pub use frozen::{FrozenCons, FrozenProd};
+pub use page::PageCons;
 pub use traits::*;
@@ -0,0 +1,71 @@
+//! Page consumer implementation.
+//!
+//! Reads bytes from the ring buffer by fixed-size pages.
+
+use crate::traits::Consumer;
+use core::{mem::ManuallyDrop, ptr};
+
+/// Consumer adapter that reads bytes by contiguous pages of `PAGE` size.
+///
+/// If a page is contiguous in the ring buffer memory then it is returned in-place without copying.
+/// If the occupied region wraps around within the page then the page is moved into an internal buffer.
+pub struct PageCons<C: Consumer<Item = u8>, const PAGE: usize> {
+    cons: C,
+    page: [u8; PAGE],
+    pending: usize,
+}
+
+impl<C: Consumer<Item = u8>, const PAGE: usize> PageCons<C, PAGE> {
+    /// Create a new page consumer.
+    ///
+    /// *Panics if `PAGE` is zero.*
+    pub fn new(cons: C) -> Self {
+        assert!(PAGE > 0, "page size must be non-zero");
+        Self {
+            cons,
+            page: [0; PAGE],
+            pending: 0,
+        }
+    }
+
+    /// Returns the next full page of bytes removing it from the ring buffer.
+    ///
+    /// Returns `None` if there are less than `PAGE` bytes in the ring buffer.
+    ///
+    /// *Bytes of an in-place page are released to the producer on the next call or when the consumer is dropped.*
+    pub fn next_page(&mut self) -> Option<&[u8]> {
+        self.release();
+        if self.cons.occupied_len() < PAGE {
+            return None;
+        }
+        if self.cons.as_slices().0.len() >= PAGE {
+            self.pending = PAGE;
+            Some(&self.cons.as_slices().0[..PAGE])
+        } else {
+            let count = self.cons.pop_slice(&mut self.page);
+            debug_assert_eq!(count, PAGE);
+            Some(&self.page)
+        }
+    }
+
+    /// Releases the bytes of the last in-place page to the producer.
+    pub fn release(&mut self) {
+        if self.pending > 0 {
+            unsafe { self.cons.advance_read_index(self.pending) };
+            self.pending = 0;
+        }
+    }
+
+    /// Releases the last page and returns underlying consumer.
+    pub fn into_inner(mut self) -> C {
+        self.release();
+        let this = ManuallyDrop::new(self);
+        unsafe { ptr::read(&this.cons) }
+    }
+}
+
+impl<C: Consumer<Item = u8>, const PAGE: usize> Drop for PageCons<C, PAGE> {
+    fn drop(&mut self) {
+        self.release();
+    }
+}